@@ -15,6 +15,7 @@ pub use transfer::*;
 pub use types::*;
 
 use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env};
+use tycoon_lib::ownership;
 
 #[contract]
 pub struct TycoonCollectibles;
@@ -424,12 +425,58 @@ impl TycoonCollectibles {
         admin.require_auth();
 
         set_minter(&env, &new_minter);
+        #[allow(deprecated)]
         env.events()
             .publish((symbol_short!("minter"), symbol_short!("set")), new_minter);
 
         Ok(())
     }
 
+    /// Get the current admin
+    pub fn get_admin(env: Env) -> Address {
+        get_admin(&env)
+    }
+
+    /// Move the admin stored by a release that predates
+    /// `tycoon_lib::ownership` into ownership storage (legacy admin only).
+    /// Run once after upgrading such a deployment.
+    pub fn migrate_admin(env: Env) -> Result<Address, CollectibleError> {
+        if ownership::is_owner_set(&env) {
+            return Err(CollectibleError::AlreadyInitialized);
+        }
+        let admin = take_legacy_admin(&env).ok_or(CollectibleError::Unauthorized)?;
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        Ok(admin)
+    }
+
+    /// Propose a new admin (admin only). Takes effect once the proposed
+    /// address calls `accept_ownership`.
+    pub fn transfer_ownership(env: Env, new_admin: Address) {
+        ownership::transfer_ownership(&env, &new_admin);
+    }
+
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_ownership(env: Env) {
+        ownership::accept_ownership(&env);
+    }
+
+    /// Withdraw a pending admin transfer (admin only)
+    pub fn cancel_ownership_transfer(env: Env) {
+        ownership::cancel_ownership_transfer(&env);
+    }
+
+    /// Permanently give up admin rights (admin only)
+    pub fn renounce_ownership(env: Env) {
+        ownership::renounce_ownership(&env);
+    }
+
+    /// Get the admin proposed by `transfer_ownership`, if any
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        ownership::pending_owner(&env)
+    }
+
     /// Get the current stock for a collectible
     pub fn get_stock(env: Env, token_id: u128) -> u64 {
         get_shop_stock(&env, token_id)
@@ -1,7 +1,9 @@
 use crate::types::Perk;
 use soroban_sdk::{Address, Env, Vec};
+use tycoon_lib::ownership;
 
-const ADMIN_KEY: &str = "ADMIN";
+/// Admin key used before `tycoon_lib::ownership`; see `take_legacy_admin`
+const LEGACY_ADMIN_KEY: &str = "ADMIN";
 const MINTER_KEY: &str = "MINTER";
 const BALANCE_PREFIX: &str = "BAL";
 const PAUSED_KEY: &str = "PAUSED";
@@ -11,19 +13,27 @@ const OWNED_TOKENS_PREFIX: &str = "OWNED";
 const TOKEN_INDEX_PREFIX: &str = "TIDX";
 const NEXT_TOKEN_ID_KEY: &str = "NEXT_TID";
 
-/// Check if admin is set
+/// Check if admin is set (stays true after the admin renounces). Also true
+/// while an admin stored by an older release is waiting to be migrated.
 pub fn has_admin(env: &Env) -> bool {
-    env.storage().instance().has(&ADMIN_KEY)
+    ownership::is_owner_set(env) || env.storage().instance().has(&LEGACY_ADMIN_KEY)
 }
 
-/// Set the contract admin
+/// Set the initial contract admin
 pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&ADMIN_KEY, admin);
+    ownership::init_owner(env, admin);
 }
 
 /// Get the contract admin
 pub fn get_admin(env: &Env) -> Address {
-    env.storage().instance().get(&ADMIN_KEY).unwrap()
+    ownership::get_owner(env)
+}
+
+/// Remove and return the admin stored by an older release, if any
+pub fn take_legacy_admin(env: &Env) -> Option<Address> {
+    let admin = env.storage().instance().get(&LEGACY_ADMIN_KEY);
+    env.storage().instance().remove(&LEGACY_ADMIN_KEY);
+    admin
 }
 
 /// Check if contract is paused
@@ -1,3 +1,5 @@
+#![allow(unused_variables, clippy::assertions_on_constants)]
+
 use super::*;
 use crate::types::{Perk, CASH_TIERS};
 use soroban_sdk::{
@@ -2072,3 +2074,109 @@ fn test_perk_enum_values() {
     let _ = client.mint_collectible(&admin, &user, &10, &1); // Shield
     let _ = client.mint_collectible(&admin, &user, &11, &1); // RollExact
 }
+
+// ========================================
+// ADMIN TRANSFER TESTS
+// ========================================
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TycoonCollectibles, ());
+    let client = TycoonCollectiblesClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    client.transfer_ownership(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.pending_owner(), Some(new_admin.clone()));
+
+    client.accept_ownership();
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.pending_owner(), None);
+
+    // Only the new admin passes the stored-admin check now
+    assert_eq!(
+        client.try_set_pause(&admin, &true),
+        Err(Ok(CollectibleError::Unauthorized))
+    );
+    client.set_pause(&new_admin, &true);
+    assert!(client.is_contract_paused());
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TycoonCollectibles, ());
+    let client = TycoonCollectiblesClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    client.transfer_ownership(&Address::generate(&env));
+    client.cancel_ownership_transfer();
+
+    assert_eq!(client.pending_owner(), None);
+    assert!(client.try_accept_ownership().is_err());
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_migrate_legacy_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TycoonCollectibles, ());
+    let client = TycoonCollectiblesClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    // Admin stored by a release from before the ownership module
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&"ADMIN", &admin);
+    });
+
+    // The old admin still blocks re-initialization
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env)),
+        Err(Ok(CollectibleError::AlreadyInitialized))
+    );
+
+    assert_eq!(client.migrate_admin(), admin);
+    assert_eq!(client.get_admin(), admin);
+    client.set_pause(&admin, &true);
+    assert!(client.is_contract_paused());
+
+    assert_eq!(
+        client.try_migrate_admin(),
+        Err(Ok(CollectibleError::AlreadyInitialized))
+    );
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(CollectibleError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_renounced_admin_cannot_reinitialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TycoonCollectibles, ());
+    let client = TycoonCollectiblesClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.renounce_ownership();
+
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(CollectibleError::AlreadyInitialized))
+    );
+    assert!(client.try_set_backend_minter(&admin).is_err());
+}
@@ -14,3 +14,24 @@ pub fn emit_player_removed_from_game(env: &Env, game_id: u128, player: &Address,
     #[allow(deprecated)]
     env.events().publish(topics, turn_count);
 }
+
+/// Emit a ReferralRewarded event
+pub fn emit_referral_rewarded(env: &Env, referrer: &Address, referee: &Address, referrer_voucher: u128, referee_voucher: u128) {
+    let topics = (Symbol::new(env, "ReferralRewarded"), referrer, referee);
+    #[allow(deprecated)]
+    env.events().publish(topics, (referrer_voucher, referee_voucher));
+}
+
+/// Emit a ReferralRewardFailed event with the vouchers that were minted
+/// before the reward system rejected the rest
+pub fn emit_referral_reward_failed(
+    env: &Env,
+    referrer: &Address,
+    referee: &Address,
+    referrer_voucher: Option<u128>,
+    referee_voucher: Option<u128>,
+) {
+    let topics = (Symbol::new(env, "ReferralRewardFailed"), referrer, referee);
+    #[allow(deprecated)]
+    env.events().publish(topics, (referrer_voucher, referee_voucher));
+}
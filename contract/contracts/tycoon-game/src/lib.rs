@@ -3,8 +3,12 @@
 mod events;
 mod storage;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, String, Symbol};
-use storage::{get_backend_game_controller, get_owner, get_tyc_token, get_usdc_token, CollectibleInfo, User};
+use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, String, Symbol, Val, Vec};
+use storage::{get_backend_game_controller, get_owner, get_tyc_token, get_usdc_token, CollectibleInfo, PayoutKind, ReferralConfig, User};
+use tycoon_lib::ownership;
+
+/// Value of the TYC voucher minted by `mint_registration_voucher`
+const REGISTRATION_VOUCHER_VALUE: u128 = 2_0000000;
 
 #[contract]
 pub struct TycoonContract;
@@ -108,7 +112,11 @@ impl TycoonContract {
         storage::set_cash_tier(&env, tier, value);
     }
 
-    pub fn register_player(env: Env, username: String, caller: Address) {
+    /// Register the caller as a player, optionally referred by an already
+    /// registered player. Referral rewards are paid once the new player
+    /// completes their first game.
+    #[allow(clippy::manual_range_contains)]
+    pub fn register_player(env: Env, username: String, caller: Address, referrer: Option<Address>) {
         caller.require_auth();
 
         // Check if already registered
@@ -116,6 +124,17 @@ impl TycoonContract {
             panic!("Address already registered");
         }
 
+        // A referrer must be registered before the referee and a referrer is
+        // fixed at registration, so referral chains cannot loop back
+        if let Some(referrer) = &referrer {
+            if *referrer == caller {
+                panic!("Cannot refer yourself");
+            }
+            if !storage::is_registered(&env, referrer) {
+                panic!("Referrer not registered");
+            }
+        }
+
         // Validate username length (3-20 chars)
         let len = username.len();
         if len < 3 || len > 20 {
@@ -135,21 +154,76 @@ impl TycoonContract {
         // Store user and mark as registered
         storage::set_user(&env, &caller, &user);
         storage::set_registered(&env, &caller);
+        if let Some(referrer) = referrer {
+            storage::set_referrer(&env, &caller, &referrer);
+        }
     }
 
-    pub fn mint_registration_voucher(env: Env, player: Address) {
+    /// Set referral rewards; at most `max_per_period` referrals per referrer
+    /// are rewarded in each `period` seconds
+    pub fn set_referral_config(env: Env, referrer_reward: u128, referee_reward: u128, max_per_period: u32, period: u64) {
         let owner = get_owner(&env);
         owner.require_auth();
 
-        // Mint 2 TYC voucher via reward system
-        let reward_system = storage::get_reward_system(&env);
-        let _token_id: u128 = env.invoke_contract(
-            &reward_system,
-            &Symbol::new(&env, "mint_voucher"),
-            soroban_sdk::vec![&env, player.into_val(&env), 2_0000000u128.into_val(&env)],
+        if period == 0 {
+            panic!("Period must be positive");
+        }
+
+        storage::set_referral_config(
+            &env,
+            &ReferralConfig {
+                referrer_reward,
+                referee_reward,
+                max_per_period,
+                period,
+            },
         );
     }
 
+    pub fn get_referral_config(env: Env) -> Option<ReferralConfig> {
+        storage::get_referral_config(&env)
+    }
+
+    pub fn get_referrer(env: Env, player: Address) -> Option<Address> {
+        storage::get_referrer(&env, &player)
+    }
+
+    /// Number of referrals rewarded to `referrer` in the current period
+    pub fn get_referral_count(env: Env, referrer: Address) -> u32 {
+        match storage::get_referral_config(&env) {
+            Some(config) => storage::get_referral_count(&env, &referrer, env.ledger().timestamp() / config.period),
+            None => 0,
+        }
+    }
+
+    /// Record a finished game for a player (owner or backend controller).
+    /// A referred player's first completed game mints a voucher to them and
+    /// to their referrer, unless the referrer reached the period cap or no
+    /// referral config is set.
+    pub fn record_game_completed(env: Env, caller: Address, player: Address, won: bool) {
+        Self::require_owner_or_controller(&env, &caller);
+
+        let mut user = storage::get_user(&env, &player).expect("Player not registered");
+        user.games_played += 1;
+        if won {
+            user.games_won += 1;
+        }
+        storage::set_user(&env, &player, &user);
+
+        if user.games_played == 1 {
+            Self::reward_referral(&env, &player);
+        }
+    }
+
+    /// Mint a 2 TYC welcome voucher to `player` (owner only). The voucher is
+    /// soulbound so bonuses from many accounts cannot be pooled into one.
+    pub fn mint_registration_voucher(env: Env, player: Address) {
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        Self::mint_voucher(&env, &player, REGISTRATION_VOUCHER_VALUE, false);
+    }
+
     pub fn get_user(env: Env, address: Address) -> Option<User> {
         storage::get_user(&env, &address)
     }
@@ -161,29 +235,130 @@ impl TycoonContract {
         storage::set_backend_game_controller(&env, &new_controller);
     }
 
+    pub fn get_owner(env: Env) -> Address {
+        get_owner(&env)
+    }
+
+    /// Propose a new owner; takes effect once they call `accept_ownership`
+    pub fn transfer_ownership(env: Env, new_owner: Address) {
+        ownership::transfer_ownership(&env, &new_owner);
+    }
+
+    pub fn accept_ownership(env: Env) {
+        ownership::accept_ownership(&env);
+    }
+
+    pub fn cancel_ownership_transfer(env: Env) {
+        ownership::cancel_ownership_transfer(&env);
+    }
+
+    /// Permanently give up ownership; owner-only functions stop working
+    pub fn renounce_ownership(env: Env) {
+        ownership::renounce_ownership(&env);
+    }
+
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        ownership::pending_owner(&env)
+    }
+
     pub fn remove_player_from_game(env: Env, caller: Address, game_id: u128, player: Address, turn_count: u32) {
+        Self::require_owner_or_controller(&env, &caller);
+
+        // Stub implementation - no payout logic yet
+        // Future: Calculate payout based on turn_count and game state
+        // Future: Transfer tokens to player
+        // Future: Update game state
+
+        // Emit event
+        events::emit_player_removed_from_game(&env, game_id, &player, turn_count);
+    }
+}
+
+impl TycoonContract {
+    #[allow(clippy::unnecessary_map_or)]
+    fn require_owner_or_controller(env: &Env, caller: &Address) {
         // Require authentication from the caller
         caller.require_auth();
 
         // Get owner and backend controller
-        let owner = get_owner(&env);
-        let backend_controller = get_backend_game_controller(&env);
+        let owner = get_owner(env);
+        let backend_controller = get_backend_game_controller(env);
 
         // Check authorization: caller must be owner OR backend controller
-        let is_owner = caller == owner;
-        let is_backend_controller = backend_controller.map_or(false, |controller| caller == controller);
+        let is_owner = *caller == owner;
+        let is_backend_controller = backend_controller.map_or(false, |controller| *caller == controller);
 
         if !is_owner && !is_backend_controller {
             panic!("Unauthorized: caller must be owner or backend game controller");
         }
+    }
 
-        // Stub implementation - no payout logic yet
-        // Future: Calculate payout based on turn_count and game state
-        // Future: Transfer tokens to player
-        // Future: Update game state
+    /// Mint a TYC voucher through the reward system, where this contract must
+    /// be the backend minter
+    fn mint_voucher(env: &Env, to: &Address, value: u128, transferable: bool) -> u128 {
+        let reward_system = storage::get_reward_system(env);
+        env.invoke_contract(
+            &reward_system,
+            &Symbol::new(env, "mint_voucher"),
+            Self::mint_voucher_args(env, to, value, transferable),
+        )
+    }
 
-        // Emit event
-        events::emit_player_removed_from_game(&env, game_id, &player, turn_count);
+    /// Like `mint_voucher`, but returns `None` instead of reverting when the
+    /// reward system rejects the mint
+    fn try_mint_voucher(env: &Env, to: &Address, value: u128, transferable: bool) -> Option<u128> {
+        let reward_system = storage::get_reward_system(env);
+        let result = env.try_invoke_contract::<u128, soroban_sdk::Error>(
+            &reward_system,
+            &Symbol::new(env, "mint_voucher"),
+            Self::mint_voucher_args(env, to, value, transferable),
+        );
+        match result {
+            Ok(Ok(token_id)) => Some(token_id),
+            _ => None,
+        }
+    }
+
+    fn mint_voucher_args(env: &Env, to: &Address, value: u128, transferable: bool) -> Vec<Val> {
+        soroban_sdk::vec![
+            env,
+            env.current_contract_address().into_val(env),
+            to.into_val(env),
+            value.into_val(env),
+            None::<u64>.into_val(env),
+            PayoutKind::Tyc.into_val(env),
+            transferable.into_val(env),
+        ]
+    }
+
+    /// Pays the referral rewards for `referee`'s first completed game. A
+    /// failing reward system must not undo the game result, so failed mints
+    /// are reported with a ReferralRewardFailed event for the backend to
+    /// settle instead of reverting.
+    fn reward_referral(env: &Env, referee: &Address) {
+        let Some(referrer) = storage::get_referrer(env, referee) else {
+            return;
+        };
+        let Some(config) = storage::get_referral_config(env) else {
+            return;
+        };
+
+        let period = env.ledger().timestamp() / config.period;
+        let count = storage::get_referral_count(env, &referrer, period);
+        if count >= config.max_per_period {
+            return;
+        }
+        storage::set_referral_count(env, &referrer, period, count + 1);
+
+        // Referral vouchers are soulbound so farmed accounts cannot pool them
+        let referrer_voucher = Self::try_mint_voucher(env, &referrer, config.referrer_reward, false);
+        let referee_voucher = Self::try_mint_voucher(env, referee, config.referee_reward, false);
+        match (referrer_voucher, referee_voucher) {
+            (Some(referrer_voucher), Some(referee_voucher)) => {
+                events::emit_referral_rewarded(env, &referrer, referee, referrer_voucher, referee_voucher)
+            }
+            _ => events::emit_referral_reward_failed(env, &referrer, referee, referrer_voucher, referee_voucher),
+        }
     }
 }
 
@@ -1,11 +1,11 @@
 #![allow(dead_code)]
 use soroban_sdk::{contracttype, Address, Env, String};
+use tycoon_lib::ownership;
 
 /// Storage keys for the contract
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    Owner,
     TycToken,
     UsdcToken,
     IsInitialized,
@@ -15,6 +15,9 @@ pub enum DataKey {
     Registered(Address), // address -> bool
     RewardSystem,      // reward system contract address
     BackendGameController, // backend game controller address
+    Referrer(Address),     // referee -> referrer
+    ReferralConfig,        // ReferralConfig
+    ReferralCount(Address, u64), // (referrer, period) -> rewarded referrals
 }
 
 /// Information about a collectible NFT
@@ -40,14 +43,33 @@ pub struct User {
     pub games_won: u32,
 }
 
-/// Get the owner address from storage
+/// Referral reward settings
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReferralConfig {
+    pub referrer_reward: u128,   // TYC voucher value for the referrer
+    pub referee_reward: u128,    // TYC voucher value for the referee
+    pub max_per_period: u32,     // rewarded referrals per referrer per period
+    pub period: u64,             // period length in seconds
+}
+
+/// Mirrors tycoon_reward_system::PayoutKind for cross-contract calls
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum PayoutKind {
+    Tyc,
+    Usdc,
+    Collectible(u128),
+}
+
+/// Get the owner address (kept by tycoon_lib::ownership)
 pub fn get_owner(env: &Env) -> Address {
-    env.storage().instance().get(&DataKey::Owner).unwrap()
+    ownership::get_owner(env)
 }
 
-/// Set the owner address in storage
+/// Set the initial owner address
 pub fn set_owner(env: &Env, owner: &Address) {
-    env.storage().instance().set(&DataKey::Owner, owner);
+    ownership::init_owner(env, owner);
 }
 
 /// Get the TYC token address from storage
@@ -162,3 +184,42 @@ pub fn get_backend_game_controller(env: &Env) -> Option<Address> {
 pub fn set_backend_game_controller(env: &Env, address: &Address) {
     env.storage().instance().set(&DataKey::BackendGameController, address);
 }
+
+/// Get the player who referred `referee`
+pub fn get_referrer(env: &Env, referee: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(referee.clone()))
+}
+
+/// Set the player who referred `referee`
+pub fn set_referrer(env: &Env, referee: &Address, referrer: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referrer(referee.clone()), referrer);
+}
+
+/// Get referral reward settings
+pub fn get_referral_config(env: &Env) -> Option<ReferralConfig> {
+    env.storage().instance().get(&DataKey::ReferralConfig)
+}
+
+/// Set referral reward settings
+pub fn set_referral_config(env: &Env, config: &ReferralConfig) {
+    env.storage().instance().set(&DataKey::ReferralConfig, config);
+}
+
+/// Get the number of rewarded referrals of a referrer in a period
+pub fn get_referral_count(env: &Env, referrer: &Address, period: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralCount(referrer.clone(), period))
+        .unwrap_or(0)
+}
+
+/// Set the number of rewarded referrals of a referrer in a period
+pub fn set_referral_count(env: &Env, referrer: &Address, period: u64, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReferralCount(referrer.clone(), period), &count);
+}
@@ -2,7 +2,7 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     token::{StellarAssetClient, TokenClient},
     Address, Env, String,
 };
@@ -331,7 +331,7 @@ fn test_register_player_success() {
     let player = Address::generate(&env);
     let username = String::from_str(&env, "player1");
 
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 
     let user = client.get_user(&player);
     assert!(user.is_some());
@@ -356,8 +356,8 @@ fn test_register_player_duplicate() {
     let player = Address::generate(&env);
     let username = String::from_str(&env, "player1");
 
-    client.register_player(&username, &player);
-    client.register_player(&username, &player); // Should panic
+    client.register_player(&username, &player, &None);
+    client.register_player(&username, &player, &None); // Should panic
 }
 
 #[test]
@@ -373,7 +373,7 @@ fn test_register_player_username_too_short() {
 
     let player = Address::generate(&env);
     let username = String::from_str(&env, "ab");
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 }
 
 #[test]
@@ -389,7 +389,47 @@ fn test_register_player_username_too_long() {
 
     let player = Address::generate(&env);
     let username = String::from_str(&env, "thisusernameiswaytoolong");
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
+}
+
+// ===== OWNERSHIP TESTS =====
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    let new_owner = Address::generate(&env);
+    client.transfer_ownership(&new_owner);
+    assert_eq!(client.get_owner(), owner);
+    assert_eq!(client.pending_owner(), Some(new_owner.clone()));
+
+    client.accept_ownership();
+    assert_eq!(client.get_owner(), new_owner);
+    assert_eq!(client.pending_owner(), None);
+
+    // The old owner is no longer accepted as the owner caller
+    let player = Address::generate(&env);
+    assert!(client.try_remove_player_from_game(&owner, &1, &player, &10).is_err());
+    client.remove_player_from_game(&new_owner, &1, &player, &10);
+}
+
+#[test]
+#[should_panic(expected = "Owner not set")]
+fn test_renounced_owner_cannot_set_controller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, owner, tyc_token, usdc_token) = setup_contract(&env);
+    let reward_system = Address::generate(&env);
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+
+    client.renounce_ownership();
+    client.set_backend_game_controller(&Address::generate(&env));
 }
 
 // ===== BACKEND GAME CONTROLLER TESTS =====
@@ -538,8 +578,8 @@ fn test_backend_controller_integration() {
     // Register players
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
-    client.register_player(&String::from_str(&env, "player1"), &player1);
-    client.register_player(&String::from_str(&env, "player2"), &player2);
+    client.register_player(&String::from_str(&env, "player1"), &player1, &None);
+    client.register_player(&String::from_str(&env, "player2"), &player2, &None);
 
     // Backend removes players from games
     client.remove_player_from_game(&backend_controller, &1, &player1, &5);
@@ -552,3 +592,218 @@ fn test_backend_controller_integration() {
     let events = env.events().all();
     assert!(!events.is_empty());
 }
+
+// ===== REFERRAL TESTS =====
+
+const DAY: u64 = 86_400;
+
+// Helper function to setup the contract with a real reward system that lets it mint vouchers
+fn setup_with_reward_system(env: &Env) -> (TycoonContractClient<'_>, tycoon_reward_system::TycoonRewardSystemClient<'_>) {
+    let (contract_id, client, owner, tyc_token, usdc_token) = setup_contract(env);
+
+    let reward_system = env.register(tycoon_reward_system::TycoonRewardSystem, ());
+    let reward_client = tycoon_reward_system::TycoonRewardSystemClient::new(env, &reward_system);
+    reward_client.initialize(&owner, &tyc_token, &usdc_token);
+    reward_client.set_backend_minter(&owner, &contract_id);
+
+    client.initialize(&tyc_token, &usdc_token, &owner, &reward_system);
+    (client, reward_client)
+}
+
+fn register(env: &Env, client: &TycoonContractClient, name: &str, referrer: Option<Address>) -> Address {
+    let player = Address::generate(env);
+    client.register_player(&String::from_str(env, name), &player, &referrer);
+    player
+}
+
+#[test]
+fn test_register_player_with_referrer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_with_reward_system(&env);
+
+    let referrer = register(&env, &client, "alice", None);
+    let referee = register(&env, &client, "bob", Some(referrer.clone()));
+
+    assert_eq!(client.get_referrer(&referee), Some(referrer.clone()));
+    assert_eq!(client.get_referrer(&referrer), None);
+}
+
+#[test]
+#[should_panic(expected = "Cannot refer yourself")]
+fn test_register_player_self_referral() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_with_reward_system(&env);
+
+    let player = Address::generate(&env);
+    client.register_player(&String::from_str(&env, "alice"), &player, &Some(player.clone()));
+}
+
+#[test]
+#[should_panic(expected = "Referrer not registered")]
+fn test_register_player_unregistered_referrer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_with_reward_system(&env);
+
+    register(&env, &client, "bob", Some(Address::generate(&env)));
+}
+
+#[test]
+#[should_panic(expected = "Address already registered")]
+fn test_referrer_cannot_be_referred_back() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_with_reward_system(&env);
+
+    let referrer = register(&env, &client, "alice", None);
+    let referee = register(&env, &client, "bob", Some(referrer.clone()));
+    client.register_player(&String::from_str(&env, "alice"), &referrer, &Some(referee));
+}
+
+#[test]
+fn test_first_completed_game_rewards_referral() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, reward_client) = setup_with_reward_system(&env);
+    client.set_referral_config(&300, &100, &5, &DAY);
+
+    let referrer = register(&env, &client, "alice", None);
+    let referee = register(&env, &client, "bob", Some(referrer.clone()));
+    let owner = client.get_owner();
+
+    client.record_game_completed(&owner, &referee, &true);
+
+    let referrer_vouchers = reward_client.tokens_of(&referrer);
+    let referee_vouchers = reward_client.tokens_of(&referee);
+    assert_eq!(referrer_vouchers.len(), 1);
+    assert_eq!(referee_vouchers.len(), 1);
+    let referrer_voucher = reward_client.get_voucher(&referrer_vouchers.get(0).unwrap()).unwrap();
+    assert_eq!(referrer_voucher.value, 300);
+    assert!(!referrer_voucher.transferable);
+    assert_eq!(reward_client.get_voucher(&referee_vouchers.get(0).unwrap()).unwrap().value, 100);
+    assert_eq!(client.get_referral_count(&referrer), 1);
+
+    let user = client.get_user(&referee).unwrap();
+    assert_eq!(user.games_played, 1);
+    assert_eq!(user.games_won, 1);
+
+    // Later games are not rewarded again
+    client.record_game_completed(&owner, &referee, &false);
+    assert_eq!(reward_client.tokens_of(&referrer).len(), 1);
+    assert_eq!(reward_client.tokens_of(&referee).len(), 1);
+    assert_eq!(client.get_user(&referee).unwrap().games_played, 2);
+}
+
+#[test]
+fn test_referral_rewards_capped_per_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10 * DAY);
+    let (client, reward_client) = setup_with_reward_system(&env);
+    client.set_referral_config(&300, &100, &1, &DAY);
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+
+    let referrer = register(&env, &client, "alice", None);
+    let first = register(&env, &client, "bob", Some(referrer.clone()));
+    let second = register(&env, &client, "carol", Some(referrer.clone()));
+    let third = register(&env, &client, "dave", Some(referrer.clone()));
+
+    client.record_game_completed(&controller, &first, &false);
+    client.record_game_completed(&controller, &second, &false);
+    assert_eq!(reward_client.tokens_of(&referrer).len(), 1);
+    assert_eq!(reward_client.tokens_of(&second).len(), 0);
+
+    env.ledger().set_timestamp(11 * DAY);
+    assert_eq!(client.get_referral_count(&referrer), 0);
+    client.record_game_completed(&controller, &third, &false);
+    assert_eq!(reward_client.tokens_of(&referrer).len(), 2);
+    assert_eq!(reward_client.tokens_of(&third).len(), 1);
+}
+
+#[test]
+fn test_failed_referral_reward_keeps_game_result() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, reward_client) = setup_with_reward_system(&env);
+    client.set_referral_config(&300, &100, &5, &DAY);
+
+    let referrer = register(&env, &client, "alice", None);
+    let referee = register(&env, &client, "bob", Some(referrer.clone()));
+    let owner = client.get_owner();
+
+    // The reward system no longer accepts mints from this contract
+    reward_client.clear_backend_minter(&owner);
+    client.record_game_completed(&owner, &referee, &true);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "ReferralRewardFailed"), referrer.clone(), referee.clone()).into_val(&env)
+    );
+    let vouchers: (Option<u128>, Option<u128>) = data.into_val(&env);
+    assert_eq!(vouchers, (None, None));
+
+    let user = client.get_user(&referee).unwrap();
+    assert_eq!(user.games_played, 1);
+    assert_eq!(user.games_won, 1);
+    assert!(reward_client.tokens_of(&referrer).is_empty());
+    assert!(reward_client.tokens_of(&referee).is_empty());
+}
+
+#[test]
+fn test_game_completion_without_referral_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, reward_client) = setup_with_reward_system(&env);
+
+    let referrer = register(&env, &client, "alice", None);
+    let referee = register(&env, &client, "bob", Some(referrer.clone()));
+    client.record_game_completed(&client.get_owner(), &referee, &false);
+
+    assert!(reward_client.tokens_of(&referrer).is_empty());
+    assert_eq!(client.get_user(&referee).unwrap().games_played, 1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+fn test_record_game_completed_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_with_reward_system(&env);
+
+    let player = register(&env, &client, "alice", None);
+    client.record_game_completed(&player, &player, &true);
+}
+
+#[test]
+fn test_mint_registration_voucher() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, reward_client) = setup_with_reward_system(&env);
+
+    let player = register(&env, &client, "alice", None);
+    client.mint_registration_voucher(&player);
+
+    let vouchers = reward_client.tokens_of(&player);
+    assert_eq!(vouchers.len(), 1);
+    let voucher = reward_client.get_voucher(&vouchers.get(0).unwrap()).unwrap();
+    assert_eq!(voucher.value, 2_0000000);
+    assert!(!voucher.transferable);
+}
+
+#[test]
+#[should_panic(expected = "Voucher is not transferable")]
+fn test_registration_voucher_cannot_be_transferred() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, reward_client) = setup_with_reward_system(&env);
+
+    let player = register(&env, &client, "alice", None);
+    client.mint_registration_voucher(&player);
+
+    let voucher_id = reward_client.tokens_of(&player).get(0).unwrap();
+    reward_client.transfer(&player, &Address::generate(&env), &voucher_id, &1);
+}
@@ -2,6 +2,8 @@
 
 use soroban_sdk::contracttype;
 
+pub mod ownership;
+
 // ============================================================
 // GameStatus
 // ============================================================
@@ -88,7 +90,6 @@ pub enum PlayerSymbol {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::Env;
 
     #[test]
     fn test_game_status_variants_compile_and_are_distinct() {
@@ -0,0 +1,368 @@
+//! Two-step ownership shared by the Tycoon contracts.
+//!
+//! The current owner proposes a successor with [`transfer_ownership`], and
+//! control only moves once the successor signs [`accept_ownership`]. Until
+//! then the owner can [`cancel_ownership_transfer`] or propose someone else,
+//! so a mistyped address can never take over a contract.
+//!
+//! Contracts call [`init_owner`] from their `initialize`, guard admin
+//! entrypoints with [`require_owner`] and expose the transfer functions as
+//! entrypoints of the same name.
+
+use soroban_sdk::{contractevent, contracttype, Address, Env};
+
+// `Owner` encodes like the instance `DataKey::Owner` that tycoon-game and
+// tycoon-main-game used before this module, and `Some(owner)` like `owner`,
+// so their existing owners are read without a migration.
+#[contracttype]
+#[derive(Clone)]
+enum OwnershipKey {
+    /// `Option<Address>`; `None` once ownership has been renounced.
+    Owner,
+    PendingOwner,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+/// Emitted when the owner proposes `new_owner` as its successor.
+#[contractevent(topics = ["OwnershipTransferStarted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferStarted {
+    #[topic]
+    pub previous_owner: Address,
+    #[topic]
+    pub new_owner: Address,
+}
+
+/// Emitted when ownership changes hands. `new_owner` is `None` when the
+/// owner renounced ownership.
+#[contractevent(topics = ["OwnershipTransferred"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferred {
+    #[topic]
+    pub previous_owner: Address,
+    pub new_owner: Option<Address>,
+}
+
+/// Emitted when the owner withdraws a pending proposal.
+#[contractevent(topics = ["OwnershipTransferCancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferCancelled {
+    #[topic]
+    pub owner: Address,
+    pub pending_owner: Address,
+}
+
+// ============================================================
+// Reads
+// ============================================================
+
+/// Returns `true` once [`init_owner`] has run, even if ownership was later
+/// renounced. Contracts use this as their "already initialized" guard.
+pub fn is_owner_set(env: &Env) -> bool {
+    env.storage().instance().has(&OwnershipKey::Owner)
+}
+
+/// Current owner, or `None` if it was never set or has been renounced.
+pub fn owner(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get::<_, Option<Address>>(&OwnershipKey::Owner)
+        .flatten()
+}
+
+/// Current owner.
+///
+/// # Panics
+/// - `"Owner not set"` if the owner was never set or has been renounced.
+pub fn get_owner(env: &Env) -> Address {
+    owner(env).expect("Owner not set")
+}
+
+/// Address proposed by the owner that has not accepted yet.
+pub fn pending_owner(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&OwnershipKey::PendingOwner)
+}
+
+/// Requires the current owner's authorization and returns the owner.
+///
+/// # Panics
+/// - `"Owner not set"` if the owner was never set or has been renounced.
+pub fn require_owner(env: &Env) -> Address {
+    let owner = get_owner(env);
+    owner.require_auth();
+    owner
+}
+
+// ============================================================
+// Writes
+// ============================================================
+
+/// Records the first owner. Meant to be called once from `initialize`.
+///
+/// # Panics
+/// - `"Owner already set"` if called a second time.
+pub fn init_owner(env: &Env, owner: &Address) {
+    if is_owner_set(env) {
+        panic!("Owner already set");
+    }
+    env.storage()
+        .instance()
+        .set(&OwnershipKey::Owner, &Some(owner.clone()));
+}
+
+/// Proposes `new_owner` as the next owner, replacing any earlier proposal.
+/// Requires the current owner's authorization.
+///
+/// # Panics
+/// - `"Owner not set"` if ownership was renounced.
+/// - `"New owner is already the owner"` if `new_owner` is the current owner.
+pub fn transfer_ownership(env: &Env, new_owner: &Address) {
+    let owner = require_owner(env);
+    if *new_owner == owner {
+        panic!("New owner is already the owner");
+    }
+    env.storage()
+        .instance()
+        .set(&OwnershipKey::PendingOwner, new_owner);
+
+    OwnershipTransferStarted {
+        previous_owner: owner,
+        new_owner: new_owner.clone(),
+    }
+    .publish(env);
+}
+
+/// Completes a transfer started with [`transfer_ownership`]. Requires the
+/// pending owner's authorization and returns the new owner.
+///
+/// # Panics
+/// - `"No pending ownership transfer"` if nothing was proposed.
+pub fn accept_ownership(env: &Env) -> Address {
+    let new_owner = pending_owner(env).expect("No pending ownership transfer");
+    new_owner.require_auth();
+
+    let previous_owner = get_owner(env);
+    env.storage()
+        .instance()
+        .set(&OwnershipKey::Owner, &Some(new_owner.clone()));
+    env.storage().instance().remove(&OwnershipKey::PendingOwner);
+
+    OwnershipTransferred {
+        previous_owner,
+        new_owner: Some(new_owner.clone()),
+    }
+    .publish(env);
+    new_owner
+}
+
+/// Withdraws the pending proposal. Requires the current owner's
+/// authorization.
+///
+/// # Panics
+/// - `"No pending ownership transfer"` if nothing was proposed.
+pub fn cancel_ownership_transfer(env: &Env) {
+    let owner = require_owner(env);
+    let pending_owner = pending_owner(env).expect("No pending ownership transfer");
+    env.storage().instance().remove(&OwnershipKey::PendingOwner);
+
+    OwnershipTransferCancelled {
+        owner,
+        pending_owner,
+    }
+    .publish(env);
+}
+
+/// Gives up ownership for good, dropping any pending proposal. Every
+/// owner-only entrypoint is permanently disabled afterwards.
+pub fn renounce_ownership(env: &Env) {
+    let owner = require_owner(env);
+    env.storage()
+        .instance()
+        .set(&OwnershipKey::Owner, &None::<Address>);
+    env.storage().instance().remove(&OwnershipKey::PendingOwner);
+
+    OwnershipTransferred {
+        previous_owner: owner,
+        new_owner: None,
+    }
+    .publish(env);
+}
+
+// ============================================================
+// Tests
+// ============================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl,
+        testutils::{Address as _, Events as _},
+        Env, IntoVal, Map, Symbol, Val, Vec,
+    };
+
+    #[contract]
+    struct Owned;
+
+    #[contractimpl]
+    impl Owned {
+        pub fn init(env: Env, owner: Address) {
+            init_owner(&env, &owner);
+        }
+
+        pub fn transfer_ownership(env: Env, new_owner: Address) {
+            super::transfer_ownership(&env, &new_owner);
+        }
+
+        pub fn accept_ownership(env: Env) {
+            super::accept_ownership(&env);
+        }
+
+        pub fn cancel_ownership_transfer(env: Env) {
+            super::cancel_ownership_transfer(&env);
+        }
+
+        pub fn renounce_ownership(env: Env) {
+            super::renounce_ownership(&env);
+        }
+
+        pub fn owner(env: Env) -> Option<Address> {
+            super::owner(&env)
+        }
+
+        pub fn pending_owner(env: Env) -> Option<Address> {
+            super::pending_owner(&env)
+        }
+
+        pub fn owner_only(env: Env) {
+            require_owner(&env);
+        }
+    }
+
+    fn setup(env: &Env) -> (OwnedClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(Owned, ());
+        let client = OwnedClient::new(env, &contract_id);
+        let owner = Address::generate(env);
+        client.init(&owner);
+        (client, contract_id, owner)
+    }
+
+    #[test]
+    fn test_two_step_transfer() {
+        let env = Env::default();
+        let (client, contract_id, owner) = setup(&env);
+        let new_owner = Address::generate(&env);
+
+        client.transfer_ownership(&new_owner);
+        let (emitter, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(emitter, contract_id);
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&env, "OwnershipTransferStarted"),
+                owner.clone(),
+                new_owner.clone()
+            )
+                .into_val(&env)
+        );
+        assert_eq!(env.auths()[0].0, owner);
+        assert_eq!(client.owner(), Some(owner.clone()));
+        assert_eq!(client.pending_owner(), Some(new_owner.clone()));
+
+        client.accept_ownership();
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let expected: Vec<Val> = (Symbol::new(&env, "OwnershipTransferred"), owner).into_val(&env);
+        assert_eq!(topics, expected);
+        let data: Map<Symbol, Option<Address>> = data.into_val(&env);
+        assert_eq!(
+            data.get(Symbol::new(&env, "new_owner")),
+            Some(Some(new_owner.clone()))
+        );
+        assert_eq!(env.auths()[0].0, new_owner);
+        assert_eq!(client.owner(), Some(new_owner));
+        assert_eq!(client.pending_owner(), None);
+    }
+
+    #[test]
+    fn test_proposal_can_be_replaced() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        let typo = Address::generate(&env);
+        let new_owner = Address::generate(&env);
+
+        client.transfer_ownership(&typo);
+        client.transfer_ownership(&new_owner);
+        client.accept_ownership();
+
+        assert_eq!(client.owner(), Some(new_owner));
+    }
+
+    #[test]
+    fn test_cancel_transfer() {
+        let env = Env::default();
+        let (client, _, owner) = setup(&env);
+
+        client.transfer_ownership(&Address::generate(&env));
+        client.cancel_ownership_transfer();
+
+        assert_eq!(client.pending_owner(), None);
+        assert!(client.try_accept_ownership().is_err());
+        assert_eq!(client.owner(), Some(owner));
+    }
+
+    #[test]
+    #[should_panic(expected = "No pending ownership transfer")]
+    fn test_cancel_without_pending_transfer() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        client.cancel_ownership_transfer();
+    }
+
+    #[test]
+    #[should_panic(expected = "New owner is already the owner")]
+    fn test_cannot_propose_current_owner() {
+        let env = Env::default();
+        let (client, _, owner) = setup(&env);
+        client.transfer_ownership(&owner);
+    }
+
+    #[test]
+    fn test_only_pending_owner_can_accept() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+
+        client.transfer_ownership(&Address::generate(&env));
+        env.set_auths(&[]);
+
+        assert!(client.try_accept_ownership().is_err());
+    }
+
+    #[test]
+    fn test_renounce_disables_owner_functions() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+
+        client.transfer_ownership(&Address::generate(&env));
+        client.renounce_ownership();
+
+        assert_eq!(client.owner(), None);
+        assert_eq!(client.pending_owner(), None);
+        assert!(client.try_owner_only().is_err());
+        assert!(client
+            .try_transfer_ownership(&Address::generate(&env))
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Owner already set")]
+    fn test_cannot_init_twice_even_after_renounce() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        client.renounce_ownership();
+        client.init(&Address::generate(&env));
+    }
+}
@@ -1,4 +1,36 @@
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+
+/// Data payload for PlayerJoined event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerJoinedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub stake_paid: u128,
+    pub joined_players: u32,
+}
+
+/// Emits PlayerJoined when a player successfully joins a pending game.
+pub fn emit_player_joined(env: &Env, data: &PlayerJoinedData) {
+    let topics = (Symbol::new(env, "PlayerJoined"), data.player.clone());
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for GameCodeAssigned event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameCodeAssignedData {
+    pub game_id: u64,
+    pub code: String,
+}
+
+/// Emits GameCodeAssigned when a pending game is given a new join code.
+pub fn emit_game_code_assigned(env: &Env, data: &GameCodeAssignedData) {
+    let topics = (Symbol::new(env, "GameCodeAssigned"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
 
 /// Data payload for PlayerLeftPending event.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,6 +49,41 @@ pub fn emit_player_left_pending(env: &Env, data: &PlayerLeftPendingData) {
     env.events().publish(topics, data);
 }
 
+/// Data payload for PlayerKicked event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlayerKickedData {
+    pub game_id: u64,
+    pub player: Address,
+    pub stake_refunded: u128,
+    pub remaining_players: u32,
+    pub banned: bool,
+}
+
+/// Emits PlayerKicked when the creator removes a player from a pending game.
+pub fn emit_player_kicked(env: &Env, data: &PlayerKickedData) {
+    let topics = (Symbol::new(env, "PlayerKicked"), data.player.clone());
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
+/// Data payload for CreatorChanged event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CreatorChangedData {
+    pub game_id: u64,
+    pub previous_creator: Address,
+    pub new_creator: Address,
+}
+
+/// Emits CreatorChanged when creator rights pass to another player after the
+/// creator leaves a pending game.
+pub fn emit_creator_changed(env: &Env, data: &CreatorChangedData) {
+    let topics = (Symbol::new(env, "CreatorChanged"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
+
 /// Data payload for PendingGameEnded event — emitted when the last player
 /// leaves and the lobby is automatically closed.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -31,3 +98,21 @@ pub fn emit_pending_game_ended(env: &Env, data: &PendingGameEndedData) {
     #[allow(deprecated)]
     env.events().publish(topics, data);
 }
+
+/// Data payload for PendingGameCancelled event — emitted when a pending game
+/// is cancelled and every joined player's stake is refunded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingGameCancelledData {
+    pub game_id: u64,
+    pub cancelled_by: Address,
+    pub stake_refunded: u128,
+    pub refunded_players: Vec<Address>,
+}
+
+/// Emits PendingGameCancelled when a pending game is cancelled.
+pub fn emit_pending_game_cancelled(env: &Env, data: &PendingGameCancelledData) {
+    let topics = (Symbol::new(env, "PendingGameCancelled"), data.game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, data);
+}
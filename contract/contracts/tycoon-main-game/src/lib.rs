@@ -7,8 +7,12 @@ mod storage;
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
-use storage::{Game, GameSettings, GameStatus};
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, IntoVal, String, Symbol, Vec};
+use storage::{Game, GameSettings, GameStatus, PlayerGame};
+use tycoon_lib::ownership;
+
+/// Maximum number of entries returned by a single `get_player_games` call.
+const MAX_PLAYER_GAMES_PAGE: u32 = 50;
 
 #[contract]
 pub struct TycoonMainGame;
@@ -35,6 +39,32 @@ impl TycoonMainGame {
         storage::set_initialized(&env);
     }
 
+    /// Set the backend game controller allowed to manage lobbies (owner only).
+    pub fn set_backend_game_controller(env: Env, new_controller: Address) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        storage::set_backend_game_controller(&env, &new_controller);
+    }
+
+    /// Set the tycoon-staking contract used to check lobby stake
+    /// requirements (owner only).
+    pub fn set_staking_contract(env: Env, staking_contract: Address) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        storage::set_staking_contract(&env, &staking_contract);
+    }
+
+    /// Set the age in seconds after which any pending game may be cancelled
+    /// by anyone (owner only). A TTL of zero disables expiry.
+    pub fn set_pending_game_ttl(env: Env, ttl: u64) {
+        let owner = storage::get_owner(&env);
+        owner.require_auth();
+
+        storage::set_pending_game_ttl(&env, ttl);
+    }
+
     /// Stub: Register a player for the main game.
     ///
     /// Full implementation will require auth, validate username,
@@ -43,6 +73,167 @@ impl TycoonMainGame {
         // TODO: implement full registration logic
     }
 
+    /// Give a pending game a fresh join code (owner or backend game controller).
+    ///
+    /// Generates an unused code, points it at the game and stores it in
+    /// `game.code`, releasing the game's previous code if it had one. Players
+    /// can then join with `join_game_by_code`.
+    ///
+    /// Emits `GameCodeAssigned`. Returns the new code.
+    ///
+    /// # Panics
+    /// - `"Unauthorized: caller must be owner or backend game controller"`.
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not pending"` — game has already started or ended.
+    pub fn assign_code(env: Env, caller: Address, game_id: u64) -> String {
+        caller.require_auth();
+
+        let is_owner = caller == storage::get_owner(&env);
+        let is_backend_controller = storage::get_backend_game_controller(&env)
+            .is_some_and(|controller| caller == controller);
+        if !is_owner && !is_backend_controller {
+            panic!("Unauthorized: caller must be owner or backend game controller");
+        }
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+
+        storage::release_game_code(&env, &game.code, game_id);
+        game.code = storage::assign_game_code(&env, game_id);
+        storage::set_game(&env, &game);
+
+        events::emit_game_code_assigned(
+            &env,
+            &events::GameCodeAssignedData {
+                game_id,
+                code: game.code.clone(),
+            },
+        );
+
+        game.code
+    }
+
+    /// Require players to have at least `min_staked` TYC staked in the
+    /// staking contract to join a pending game (owner or backend game
+    /// controller). Zero removes the requirement.
+    ///
+    /// # Panics
+    /// - `"Unauthorized: caller must be owner or backend game controller"`.
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Minimum stake cannot be negative"` — `min_staked` is below zero.
+    pub fn set_min_staked(env: Env, caller: Address, game_id: u64, min_staked: i128) {
+        caller.require_auth();
+
+        let is_owner = caller == storage::get_owner(&env);
+        let is_backend_controller = storage::get_backend_game_controller(&env)
+            .is_some_and(|controller| caller == controller);
+        if !is_owner && !is_backend_controller {
+            panic!("Unauthorized: caller must be owner or backend game controller");
+        }
+
+        let game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+        if min_staked < 0 {
+            panic!("Minimum stake cannot be negative");
+        }
+
+        storage::set_min_staked(&env, game_id, min_staked);
+    }
+
+    /// Join a pending game using its short join code.
+    ///
+    /// Validates:
+    /// - The code maps to a game.
+    /// - Game status is `Pending`.
+    /// - The lobby is not full.
+    /// - `player` has not already joined and is not in another active game.
+    /// - `player` has at least the lobby's minimum TYC staked in the
+    ///   staking contract, if the lobby sets one (see `set_min_staked`).
+    ///
+    /// On success:
+    /// - Transfers `stake_per_player` in USDC from the player to the contract
+    ///   (if stake > 0).
+    /// - Appends the player to `joined_players` and increments `total_staked`.
+    /// - Records the game in the player's game history.
+    /// - Emits `PlayerJoined`.
+    ///
+    /// Returns the ID of the joined game.
+    ///
+    /// # Panics
+    /// - `"Invalid game code"` — no game uses this code.
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Game is full"` — all player slots are taken.
+    /// - `"Player already in this game"` — caller has already joined.
+    /// - `"Player is banned from this game"` — caller was kicked and banned.
+    /// - `"Player already in an active game"` — caller is in another game.
+    /// - `"Staking contract not set"` — the lobby requires a staked amount
+    ///   but no staking contract is configured.
+    /// - `"Insufficient staked balance"` — caller has staked less than the
+    ///   lobby's minimum.
+    pub fn join_game_by_code(env: Env, player: Address, code: String) -> u64 {
+        player.require_auth();
+
+        let game_id = storage::get_game_id_by_code(&env, &code)
+            .unwrap_or_else(|| panic!("Invalid game code"));
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        // The index must agree with the game's own code
+        if game.code != code {
+            panic!("Invalid game code");
+        }
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+        if game.joined_players.len() >= game.number_of_players {
+            panic!("Game is full");
+        }
+        if game.joined_players.contains(&player) {
+            panic!("Player already in this game");
+        }
+        if storage::is_banned(&env, game_id, &player) {
+            panic!("Player is banned from this game");
+        }
+        let min_staked = storage::get_min_staked(&env, game_id);
+        if min_staked > 0 && Self::staked_balance(&env, &player) < min_staked {
+            panic!("Insufficient staked balance");
+        }
+
+        storage::record_game_joined(&env, &player, game_id);
+
+        // Collect stake if applicable — transfer from player to contract
+        if game.stake_per_player > 0 {
+            let usdc_token = storage::get_usdc_token(&env);
+            let token_client = token::Client::new(&env, &usdc_token);
+            let contract_address = env.current_contract_address();
+            token_client.transfer(&player, &contract_address, &(game.stake_per_player as i128));
+        }
+
+        game.joined_players.push_back(player.clone());
+        game.total_staked = game
+            .total_staked
+            .checked_add(game.stake_per_player)
+            .expect("Total staked overflow");
+
+        storage::set_game(&env, &game);
+
+        events::emit_player_joined(
+            &env,
+            &events::PlayerJoinedData {
+                game_id,
+                player,
+                stake_paid: game.stake_per_player,
+                joined_players: game.joined_players.len(),
+            },
+        );
+
+        game_id
+    }
+
     /// Allow a player to leave a pending (not yet started) game.
     ///
     /// Validates:
@@ -54,7 +245,12 @@ impl TycoonMainGame {
     /// - Refunds `stake_per_player` in USDC to the leaving player (if stake > 0).
     /// - Removes the player from `joined_players`.
     /// - Decrements `total_staked` by `stake_per_player`.
-    /// - If no players remain, sets game status to `Ended` with current timestamp.
+    /// - Marks the game as left in the player's game history and clears their
+    ///   active game.
+    /// - If the creator leaves while others remain, transfers creator rights to
+    ///   the next player in join order and emits `CreatorChanged`.
+    /// - If no players remain, sets game status to `Ended` with current timestamp
+    ///   and releases the game's join code.
     /// - Emits `PlayerLeftPending` event always.
     /// - Emits `PendingGameEnded` event if the lobby is now empty.
     ///
@@ -71,59 +267,175 @@ impl TycoonMainGame {
             panic!("Game is not pending");
         }
 
-        // Find and remove the player from joined_players
-        let mut new_players: Vec<Address> = Vec::new(&env);
-        let mut found = false;
+        let remaining = Self::remove_pending_player(&env, &mut game, &player);
 
-        for p in game.joined_players.iter() {
-            if p == player {
-                found = true;
-            } else {
-                new_players.push_back(p);
-            }
+        // Emit PlayerLeftPending
+        events::emit_player_left_pending(
+            &env,
+            &events::PlayerLeftPendingData {
+                game_id,
+                player: player.clone(),
+                stake_refunded: game.stake_per_player,
+                remaining_players: remaining,
+            },
+        );
+
+        // Emit PendingGameEnded if lobby is now empty
+        if remaining == 0 {
+            events::emit_pending_game_ended(&env, &events::PendingGameEndedData { game_id });
         }
+    }
 
-        if !found {
-            panic!("Player is not in this game");
+    /// Allow the creator to remove another player from a pending game.
+    ///
+    /// Uses the same refund path as `leave_pending_game`: the kicked player's
+    /// stake is refunded and the game is marked as left in their game history.
+    /// If `ban` is true the player cannot rejoin this game's lobby.
+    ///
+    /// Emits `PlayerKicked`.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Unauthorized: only the creator can kick players"` — `creator` is
+    ///   not the game's creator.
+    /// - `"Cannot kick the creator"` — `player` is the creator.
+    /// - `"Player is not in this game"` — `player` has not joined.
+    pub fn kick_from_pending(env: Env, game_id: u64, creator: Address, player: Address, ban: bool) {
+        creator.require_auth();
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+        if creator != game.creator {
+            panic!("Unauthorized: only the creator can kick players");
+        }
+        if player == game.creator {
+            panic!("Cannot kick the creator");
         }
 
-        // Refund stake if applicable — transfer from contract to player
+        let remaining = Self::remove_pending_player(&env, &mut game, &player);
+
+        if ban {
+            storage::set_banned(&env, game_id, &player);
+        }
+
+        events::emit_player_kicked(
+            &env,
+            &events::PlayerKickedData {
+                game_id,
+                player,
+                stake_refunded: game.stake_per_player,
+                remaining_players: remaining,
+                banned: ban,
+            },
+        );
+    }
+
+    /// Cancel a pending game, refunding every joined player's stake.
+    ///
+    /// `caller` must be the game's creator, the contract owner or the backend
+    /// game controller — unless the game has been pending for longer than the
+    /// configured TTL, in which case anyone may cancel it.
+    ///
+    /// On success:
+    /// - Refunds `stake_per_player` in USDC to every joined player (if stake > 0).
+    /// - Sets `total_staked` to zero and status to `Ended` with current timestamp.
+    /// - Releases the game's join code and clears each player's active game.
+    /// - Emits `PendingGameCancelled` with the list of refunded players.
+    ///
+    /// # Panics
+    /// - `"Game not found"` — game ID does not exist.
+    /// - `"Game is not pending"` — game has already started or ended.
+    /// - `"Unauthorized: caller cannot cancel this game"` — caller is not
+    ///   allowed to cancel and the game has not expired.
+    pub fn cancel_pending_game(env: Env, game_id: u64, caller: Address) {
+        caller.require_auth();
+
+        let mut game = storage::get_game(&env, game_id).unwrap_or_else(|| panic!("Game not found"));
+
+        if !matches!(game.status, GameStatus::Pending) {
+            panic!("Game is not pending");
+        }
+
+        let is_creator = caller == game.creator;
+        let is_owner = caller == storage::get_owner(&env);
+        let is_backend_controller = storage::get_backend_game_controller(&env)
+            .is_some_and(|controller| caller == controller);
+
+        let ttl = storage::get_pending_game_ttl(&env);
+        let now = env.ledger().timestamp();
+        let is_expired = ttl > 0 && now.saturating_sub(game.created_at) > ttl;
+
+        if !is_creator && !is_owner && !is_backend_controller && !is_expired {
+            panic!("Unauthorized: caller cannot cancel this game");
+        }
+
+        // Refund every joined player's stake — transfer from contract to player
         if game.stake_per_player > 0 {
             let usdc_token = storage::get_usdc_token(&env);
             let token_client = token::Client::new(&env, &usdc_token);
             let contract_address = env.current_contract_address();
-            token_client.transfer(&contract_address, &player, &(game.stake_per_player as i128));
+            for p in game.joined_players.iter() {
+                token_client.transfer(&contract_address, &p, &(game.stake_per_player as i128));
+            }
         }
 
-        // Update game state
-        game.total_staked = game.total_staked.saturating_sub(game.stake_per_player);
-        game.joined_players = new_players;
-
-        let remaining = game.joined_players.len() as u32;
-
-        // If no players remain, end the game automatically
-        if remaining == 0 {
-            game.status = GameStatus::Ended;
-            game.ended_at = env.ledger().timestamp();
+        for p in game.joined_players.iter() {
+            storage::clear_active_game(&env, &p, game_id);
         }
 
+        game.total_staked = 0;
+        game.status = GameStatus::Ended;
+        game.ended_at = now;
+        storage::release_game_code(&env, &game.code, game_id);
         storage::set_game(&env, &game);
 
-        // Emit PlayerLeftPending
-        events::emit_player_left_pending(
+        events::emit_pending_game_cancelled(
             &env,
-            &events::PlayerLeftPendingData {
+            &events::PendingGameCancelledData {
                 game_id,
-                player: player.clone(),
+                cancelled_by: caller,
                 stake_refunded: game.stake_per_player,
-                remaining_players: remaining,
+                refunded_players: game.joined_players,
             },
         );
+    }
 
-        // Emit PendingGameEnded if lobby is now empty
-        if remaining == 0 {
-            events::emit_pending_game_ended(&env, &events::PendingGameEndedData { game_id });
-        }
+    // -----------------------------------------------------------------------
+    // Ownership (two-step, see tycoon_lib::ownership)
+    // -----------------------------------------------------------------------
+
+    /// Propose `new_owner` as the next owner (owner only). Control does not
+    /// move until `new_owner` calls `accept_ownership`; proposing again
+    /// replaces the pending proposal.
+    ///
+    /// Emits `OwnershipTransferStarted`.
+    pub fn transfer_ownership(env: Env, new_owner: Address) {
+        ownership::transfer_ownership(&env, &new_owner);
+    }
+
+    /// Complete a pending transfer. Must be signed by the proposed owner.
+    ///
+    /// Emits `OwnershipTransferred`.
+    ///
+    /// # Panics
+    /// - `"No pending ownership transfer"` if nothing was proposed.
+    pub fn accept_ownership(env: Env) {
+        ownership::accept_ownership(&env);
+    }
+
+    /// Withdraw the pending proposal (owner only).
+    pub fn cancel_ownership_transfer(env: Env) {
+        ownership::cancel_ownership_transfer(&env);
+    }
+
+    /// Give up ownership permanently (owner only). Owner-only entrypoints
+    /// panic with `"Owner not set"` afterwards.
+    pub fn renounce_ownership(env: Env) {
+        ownership::renounce_ownership(&env);
     }
 
     // -----------------------------------------------------------------------
@@ -135,6 +447,11 @@ impl TycoonMainGame {
         storage::get_owner(&env)
     }
 
+    /// Returns the owner proposed by `transfer_ownership`, if any.
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        ownership::pending_owner(&env)
+    }
+
     /// Returns the reward system contract address stored during initialization.
     pub fn get_reward_system(env: Env) -> Address {
         storage::get_reward_system(&env)
@@ -145,6 +462,26 @@ impl TycoonMainGame {
         storage::is_registered(&env, &address)
     }
 
+    /// Returns the backend game controller address, if one has been set.
+    pub fn get_backend_game_controller(env: Env) -> Option<Address> {
+        storage::get_backend_game_controller(&env)
+    }
+
+    /// Returns the staking contract address, if one has been set.
+    pub fn get_staking_contract(env: Env) -> Option<Address> {
+        storage::get_staking_contract(&env)
+    }
+
+    /// Returns the pending game TTL in seconds. Zero means expiry is disabled.
+    pub fn get_pending_game_ttl(env: Env) -> u64 {
+        storage::get_pending_game_ttl(&env)
+    }
+
+    /// Returns true if `player` has been banned from the lobby of `game_id`.
+    pub fn is_banned_from_game(env: Env, game_id: u64, player: Address) -> bool {
+        storage::is_banned(&env, game_id, &player)
+    }
+
     /// Retrieves a game by ID. Returns `None` if not found.
     pub fn get_game(env: Env, game_id: u64) -> Option<Game> {
         storage::get_game(&env, game_id)
@@ -154,4 +491,133 @@ impl TycoonMainGame {
     pub fn get_game_settings(env: Env, game_id: u64) -> Option<GameSettings> {
         storage::get_game_settings(&env, game_id)
     }
+
+    /// Returns the minimum TYC a player must have staked to join a game.
+    /// Zero means no requirement.
+    pub fn get_min_staked(env: Env, game_id: u64) -> i128 {
+        storage::get_min_staked(&env, game_id)
+    }
+
+    /// Retrieves the game a join code currently points at. Returns `None` if
+    /// the code is unknown or has been released.
+    pub fn get_game_by_code(env: Env, code: String) -> Option<Game> {
+        storage::get_game_id_by_code(&env, &code).and_then(|id| storage::get_game(&env, id))
+    }
+
+    /// Returns a page of the games `address` has joined, in join order.
+    ///
+    /// `cursor` is the zero-based position in the player's history to start
+    /// from; `limit` is capped at 50 entries per call. Each entry carries the
+    /// game's current status, which is its final status once it has ended,
+    /// and whether the player left the lobby before the game started.
+    pub fn get_player_games(
+        env: Env,
+        address: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<PlayerGame> {
+        let end = cursor
+            .saturating_add(limit.min(MAX_PLAYER_GAMES_PAGE))
+            .min(storage::get_player_game_count(&env, &address));
+
+        let mut page = Vec::new(&env);
+        for i in cursor..end {
+            let Some(game_id) = storage::get_player_game_at(&env, &address, i) else {
+                continue;
+            };
+            if let Some(game) = storage::get_game(&env, game_id) {
+                page.push_back(PlayerGame {
+                    game_id,
+                    status: game.status,
+                    left: storage::has_left_game(&env, &address, game_id),
+                });
+            }
+        }
+        page
+    }
+
+    /// Returns the ID of the pending or ongoing game `address` is in, or
+    /// `None` if the player is free to join another game.
+    pub fn active_game_of(env: Env, address: Address) -> Option<u64> {
+        storage::get_active_game(&env, &address)
+    }
+}
+
+impl TycoonMainGame {
+    /// Removes `player` from a pending game's lobby, refunding their stake.
+    ///
+    /// Shared by `leave_pending_game` and `kick_from_pending`. Hands creator
+    /// rights to the next player in join order if the creator is removed, and
+    /// ends the game and releases its join code if the lobby becomes empty.
+    /// Persists the updated game and returns the number of remaining players.
+    ///
+    /// # Panics
+    /// - `"Player is not in this game"` — `player` has not joined.
+    fn remove_pending_player(env: &Env, game: &mut Game, player: &Address) -> u32 {
+        // Find and remove the player from joined_players
+        let mut new_players: Vec<Address> = Vec::new(env);
+        let mut found = false;
+
+        for p in game.joined_players.iter() {
+            if p == *player {
+                found = true;
+            } else {
+                new_players.push_back(p);
+            }
+        }
+
+        if !found {
+            panic!("Player is not in this game");
+        }
+
+        // Refund stake if applicable — transfer from contract to player
+        if game.stake_per_player > 0 {
+            let usdc_token = storage::get_usdc_token(env);
+            let token_client = token::Client::new(env, &usdc_token);
+            let contract_address = env.current_contract_address();
+            token_client.transfer(&contract_address, player, &(game.stake_per_player as i128));
+        }
+
+        // Update game state
+        game.total_staked = game.total_staked.saturating_sub(game.stake_per_player);
+        game.joined_players = new_players;
+
+        #[allow(clippy::unnecessary_cast)]
+        let remaining = game.joined_players.len() as u32;
+
+        // If no players remain, end the game automatically; otherwise hand
+        // creator rights to the earliest remaining joiner if the creator left
+        if remaining == 0 {
+            game.status = GameStatus::Ended;
+            game.ended_at = env.ledger().timestamp();
+            storage::release_game_code(env, &game.code, game.id);
+        } else if *player == game.creator {
+            let new_creator = game.joined_players.get(0).unwrap();
+            game.creator = new_creator.clone();
+            events::emit_creator_changed(
+                env,
+                &events::CreatorChangedData {
+                    game_id: game.id,
+                    previous_creator: player.clone(),
+                    new_creator,
+                },
+            );
+        }
+
+        storage::set_game(env, game);
+        storage::record_game_left(env, player, game.id);
+
+        remaining
+    }
+
+    /// TYC `player` has staked in the configured staking contract.
+    fn staked_balance(env: &Env, player: &Address) -> i128 {
+        let staking_contract = storage::get_staking_contract(env)
+            .unwrap_or_else(|| panic!("Staking contract not set"));
+        env.invoke_contract(
+            &staking_contract,
+            &Symbol::new(env, "staked_balance"),
+            vec![env, player.into_val(env)],
+        )
+    }
 }
@@ -1,4 +1,5 @@
 use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use tycoon_lib::ownership;
 
 // -----------------------------------------------------------------------
 // DataKey
@@ -8,8 +9,6 @@ use soroban_sdk::{contracttype, Address, Env, String, Vec};
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    /// The contract admin/owner address.
-    Owner,
     /// The reward system contract address used for voucher minting.
     RewardSystem,
     /// The USDC token contract address used for stake refunds.
@@ -24,6 +23,30 @@ pub enum DataKey {
     GameSettings(u64),
     /// Auto-incrementing game ID counter.
     NextGameId,
+    /// Maps player -> number of games in the player's history.
+    PlayerGameCount(Address),
+    /// Maps (player, position) -> ID of the game at that position in the
+    /// player's history, in join order.
+    PlayerGameAt(Address, u32),
+    /// Marks that a game is already in the player's history.
+    PlayerGameJoined(Address, u64),
+    /// Maps player -> ID of the game the player is currently in.
+    ActiveGame(Address),
+    /// Marks that a player left (or was kicked from) a game's lobby.
+    LeftGame(Address, u64),
+    /// Maps an in-use join code -> game_id.
+    CodeIndex(String),
+    /// Backend game controller address allowed to manage lobbies.
+    BackendGameController,
+    /// Age in seconds after which a pending game may be cancelled by anyone.
+    PendingGameTtl,
+    /// Marks a player as banned from rejoining a given game's lobby.
+    GameBan(u64, Address),
+    /// The tycoon-staking contract queried for lobby stake requirements.
+    StakingContract,
+    /// Maps game_id -> minimum TYC a player must have staked to join.
+    /// Zero when unset.
+    MinStaked(u64),
 }
 
 // -----------------------------------------------------------------------
@@ -97,7 +120,8 @@ pub struct Game {
     pub id: u64,
     /// Short alphanumeric join code for sharing the lobby.
     pub code: String,
-    /// Address of the player who created the game.
+    /// Address of the player who created the game. Passes to the next player
+    /// in join order if the creator leaves while the game is pending.
     pub creator: Address,
     /// Current lifecycle status of the game.
     pub status: GameStatus,
@@ -121,6 +145,26 @@ pub struct Game {
     pub ended_at: u64,
 }
 
+// -----------------------------------------------------------------------
+// PlayerGame struct
+// -----------------------------------------------------------------------
+
+/// Entry in a player's game history, as returned by `get_player_games`.
+///
+/// `status` is read from the stored `Game` at query time, so it reflects the
+/// final status once the game has ended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerGame {
+    /// ID of the game the player joined.
+    pub game_id: u64,
+    /// Current lifecycle status of that game.
+    pub status: GameStatus,
+    /// Whether the player left or was kicked from the lobby before the game
+    /// started.
+    pub left: bool,
+}
+
 // -----------------------------------------------------------------------
 // Initialization helpers
 // -----------------------------------------------------------------------
@@ -139,16 +183,15 @@ pub fn set_initialized(env: &Env) {
 // -----------------------------------------------------------------------
 // Owner helpers
 // -----------------------------------------------------------------------
+// The owner is kept by `tycoon_lib::ownership` so it can only change through
+// the two-step transfer flow.
 
 pub fn get_owner(env: &Env) -> Address {
-    env.storage()
-        .instance()
-        .get(&DataKey::Owner)
-        .expect("Owner not set")
+    ownership::get_owner(env)
 }
 
 pub fn set_owner(env: &Env, owner: &Address) {
-    env.storage().instance().set(&DataKey::Owner, owner);
+    ownership::init_owner(env, owner);
 }
 
 // -----------------------------------------------------------------------
@@ -185,6 +228,72 @@ pub fn set_usdc_token(env: &Env, address: &Address) {
     env.storage().instance().set(&DataKey::UsdcToken, address);
 }
 
+// -----------------------------------------------------------------------
+// Backend game controller helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the backend game controller address, if one has been set.
+pub fn get_backend_game_controller(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::BackendGameController)
+}
+
+/// Stores the backend game controller address.
+pub fn set_backend_game_controller(env: &Env, address: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BackendGameController, address);
+}
+
+// -----------------------------------------------------------------------
+// Staking contract helpers
+// -----------------------------------------------------------------------
+
+/// Retrieves the staking contract address, if one has been set.
+pub fn get_staking_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::StakingContract)
+}
+
+/// Stores the staking contract address.
+pub fn set_staking_contract(env: &Env, address: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::StakingContract, address);
+}
+
+/// Retrieves the minimum staked TYC required to join a game. Zero if unset.
+pub fn get_min_staked(env: &Env, game_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MinStaked(game_id))
+        .unwrap_or(0)
+}
+
+/// Stores the minimum staked TYC required to join a game.
+pub fn set_min_staked(env: &Env, game_id: u64, min_staked: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MinStaked(game_id), &min_staked);
+}
+
+// -----------------------------------------------------------------------
+// Pending game TTL helpers
+// -----------------------------------------------------------------------
+
+/// Returns the pending game TTL in seconds. Zero means expiry is disabled.
+pub fn get_pending_game_ttl(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingGameTtl)
+        .unwrap_or(0)
+}
+
+/// Stores the pending game TTL in seconds.
+pub fn set_pending_game_ttl(env: &Env, ttl: u64) {
+    env.storage().instance().set(&DataKey::PendingGameTtl, &ttl);
+}
+
 // -----------------------------------------------------------------------
 // Player registration helpers
 // -----------------------------------------------------------------------
@@ -251,3 +360,174 @@ pub fn set_game_settings(env: &Env, game_id: u64, settings: &GameSettings) {
         .persistent()
         .set(&DataKey::GameSettings(game_id), settings);
 }
+
+// -----------------------------------------------------------------------
+// Join code helpers
+// -----------------------------------------------------------------------
+
+/// Characters used for join codes. Omits `0`/`O` and `1`/`I` so codes are
+/// easy to read aloud and type.
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Length of a generated join code.
+pub const CODE_LENGTH: usize = 6;
+
+/// Number of attempts made to find an unused code before giving up.
+const MAX_CODE_ATTEMPTS: u32 = 8;
+
+/// Returns the game ID a join code currently points at, if any.
+pub fn get_game_id_by_code(env: &Env, code: &String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CodeIndex(code.clone()))
+}
+
+/// Generates a join code from the contract PRNG that is not currently in use,
+/// reserves it for `game_id` and returns it.
+///
+/// # Panics
+/// - `"Unable to generate unique game code"` if every attempt collided.
+pub fn assign_game_code(env: &Env, game_id: u64) -> String {
+    for _ in 0..MAX_CODE_ATTEMPTS {
+        let mut bytes = [0u8; CODE_LENGTH];
+        for b in bytes.iter_mut() {
+            let index = env.prng().gen_range::<u64>(0..CODE_ALPHABET.len() as u64);
+            *b = CODE_ALPHABET[index as usize];
+        }
+        let code = String::from_bytes(env, &bytes);
+
+        if get_game_id_by_code(env, &code).is_none() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::CodeIndex(code.clone()), &game_id);
+            return code;
+        }
+    }
+    panic!("Unable to generate unique game code");
+}
+
+/// Releases a join code so it can be reused, but only if it still points at
+/// `game_id`.
+pub fn release_game_code(env: &Env, code: &String, game_id: u64) {
+    if get_game_id_by_code(env, code) == Some(game_id) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CodeIndex(code.clone()));
+    }
+}
+
+// -----------------------------------------------------------------------
+// Lobby ban helpers
+// -----------------------------------------------------------------------
+
+/// Returns true if `player` has been banned from the lobby of `game_id`.
+pub fn is_banned(env: &Env, game_id: u64, player: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GameBan(game_id, player.clone()))
+        .unwrap_or(false)
+}
+
+/// Bans `player` from rejoining the lobby of `game_id`.
+pub fn set_banned(env: &Env, game_id: u64, player: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::GameBan(game_id, player.clone()), &true);
+}
+
+// -----------------------------------------------------------------------
+// Player game index helpers
+// -----------------------------------------------------------------------
+
+/// Returns the number of games in the player's history.
+pub fn get_player_game_count(env: &Env, player: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerGameCount(player.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns the ID of the game at `index` in the player's history.
+pub fn get_player_game_at(env: &Env, player: &Address, index: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerGameAt(player.clone(), index))
+}
+
+/// Returns the ID of the pending or ongoing game the player is in, if any.
+///
+/// The stored marker is checked against the game's status, so a game that
+/// has ended no longer counts as active even if the marker was not cleared.
+pub fn get_active_game(env: &Env, player: &Address) -> Option<u64> {
+    let game_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ActiveGame(player.clone()))?;
+    match get_game(env, game_id) {
+        Some(game) if !matches!(game.status, GameStatus::Ended) => Some(game_id),
+        _ => None,
+    }
+}
+
+/// Records that `player` joined `game_id`: appends the game to the player's
+/// history and marks it as their active game. Rejoining a game the player
+/// left clears its left marker.
+///
+/// # Panics
+/// - `"Player already in an active game"` if the player is in another
+///   pending or ongoing game.
+pub fn record_game_joined(env: &Env, player: &Address, game_id: u64) {
+    if let Some(active) = get_active_game(env, player) {
+        if active != game_id {
+            panic!("Player already in an active game");
+        }
+    }
+
+    // Each game is appended once; rejoining after leaving keeps its position
+    let joined_key = DataKey::PlayerGameJoined(player.clone(), game_id);
+    if !env.storage().persistent().has(&joined_key) {
+        let count = get_player_game_count(env, player);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PlayerGameAt(player.clone(), count), &game_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PlayerGameCount(player.clone()), &(count + 1));
+        env.storage().persistent().set(&joined_key, &true);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActiveGame(player.clone()), &game_id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LeftGame(player.clone(), game_id));
+}
+
+/// Records that `player` left `game_id` before it started: the game stays in
+/// the player's history marked as left, and their active game marker is
+/// cleared if it points at that game.
+pub fn record_game_left(env: &Env, player: &Address, game_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LeftGame(player.clone(), game_id), &true);
+
+    clear_active_game(env, player, game_id);
+}
+
+/// Returns true if `player` left the lobby of `game_id` and has not rejoined.
+pub fn has_left_game(env: &Env, player: &Address, game_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LeftGame(player.clone(), game_id))
+        .unwrap_or(false)
+}
+
+/// Clears the player's active game marker if it points at `game_id`.
+pub fn clear_active_game(env: &Env, player: &Address, game_id: u64) {
+    let key = DataKey::ActiveGame(player.clone());
+    let active: Option<u64> = env.storage().persistent().get(&key);
+    if active == Some(game_id) {
+        env.storage().persistent().remove(&key);
+    }
+}
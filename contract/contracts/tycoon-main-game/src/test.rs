@@ -1,15 +1,18 @@
 #![cfg(test)]
+#![allow(clippy::bool_assert_comparison, clippy::cloned_ref_to_slice_refs)]
 
 use super::*;
 use crate::storage::{
-    get_game, get_game_settings, next_game_id, set_game, set_game_settings, Game, GameMode,
-    GameSettings, GameStatus,
+    assign_game_code, get_game, get_game_id_by_code, get_game_settings, get_player_game_at,
+    get_player_game_count, next_game_id, record_game_joined, set_game, set_game_settings,
+    set_min_staked, Game, GameMode, GameSettings, GameStatus, CODE_LENGTH,
 };
 use soroban_sdk::{
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     token::{StellarAssetClient, TokenClient},
-    Address, Env, String, Vec,
+    Address, Env, IntoVal, String, Symbol, Val, Vec,
 };
+use tycoon_staking::{TycoonStaking, TycoonStakingClient};
 
 // -----------------------------------------------------------------------
 // Test helpers
@@ -94,6 +97,19 @@ fn make_game_with_stake(
     }
 }
 
+/// Store a pending game created by `creator` with a freshly assigned join
+/// code. Returns the join code.
+fn seed_game_with_code(env: &Env, contract_id: &Address, creator: &Address, stake: u128) -> String {
+    env.as_contract(contract_id, || {
+        let id = next_game_id(env);
+        let mut game = make_game_with_stake(env, id, creator.clone(), stake, &[]);
+        game.code = assign_game_code(env, id);
+        set_game(env, &game);
+        record_game_joined(env, creator, id);
+        game.code
+    })
+}
+
 // -----------------------------------------------------------------------
 // Existing: GameSettings struct tests
 // -----------------------------------------------------------------------
@@ -803,3 +819,982 @@ fn test_leave_pending_game_non_member_panics() {
 
     client.leave_pending_game(&1, &outsider);
 }
+
+// -----------------------------------------------------------------------
+// Player game index
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_get_player_games_returns_ids_and_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let mut first = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+        record_game_joined(&env, &player, first.id);
+        first.status = GameStatus::Ended;
+        set_game(&env, &first);
+
+        let second = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+        set_game(&env, &second);
+        record_game_joined(&env, &player, second.id);
+    });
+
+    let games = client.get_player_games(&player, &0, &10);
+    assert_eq!(games.len(), 2);
+    assert_eq!(games.get(0).unwrap().game_id, 1);
+    assert_eq!(games.get(0).unwrap().status, GameStatus::Ended);
+    assert_eq!(games.get(1).unwrap().game_id, 2);
+    assert_eq!(games.get(1).unwrap().status, GameStatus::Pending);
+    assert_eq!(client.active_game_of(&player), Some(2));
+}
+
+#[test]
+fn test_get_player_games_paginates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        for _ in 0..5 {
+            let mut game = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+            game.status = GameStatus::Ended;
+            set_game(&env, &game);
+            record_game_joined(&env, &player, game.id);
+        }
+    });
+
+    let page = client.get_player_games(&player, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().game_id, 3);
+    assert_eq!(page.get(1).unwrap().game_id, 4);
+
+    assert_eq!(client.get_player_games(&player, &4, &10).len(), 1);
+    assert!(client.get_player_games(&player, &9, &10).is_empty());
+    assert!(client
+        .get_player_games(&Address::generate(&env), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_player_history_stored_one_entry_per_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        for _ in 0..3 {
+            let mut game = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+            game.status = GameStatus::Ended;
+            set_game(&env, &game);
+            record_game_joined(&env, &player, game.id);
+        }
+        // Recording the same game again does not grow the history
+        record_game_joined(&env, &player, 3);
+
+        assert_eq!(get_player_game_count(&env, &player), 3);
+        assert_eq!(get_player_game_at(&env, &player, 0), Some(1));
+        assert_eq!(get_player_game_at(&env, &player, 2), Some(3));
+        assert_eq!(get_player_game_at(&env, &player, 3), None);
+    });
+}
+
+#[test]
+fn test_active_game_of_ignores_ended_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = Address::generate(&env);
+    assert_eq!(client.active_game_of(&player), None);
+
+    env.as_contract(&contract_id, || {
+        let mut game = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+        game.status = GameStatus::Ongoing;
+        set_game(&env, &game);
+        record_game_joined(&env, &player, game.id);
+    });
+    assert_eq!(client.active_game_of(&player), Some(1));
+
+    env.as_contract(&contract_id, || {
+        let mut game = get_game(&env, 1).unwrap();
+        game.status = GameStatus::Ended;
+        set_game(&env, &game);
+    });
+    assert_eq!(client.active_game_of(&player), None);
+}
+
+#[test]
+#[should_panic(expected = "Player already in an active game")]
+fn test_record_game_joined_blocks_second_active_game() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, _, _, _, _) = setup_contract(&env);
+
+    let player = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let mut first = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+        first.status = GameStatus::Ongoing;
+        set_game(&env, &first);
+        record_game_joined(&env, &player, first.id);
+
+        let second = make_game_with_stake(&env, next_game_id(&env), player.clone(), 0, &[]);
+        set_game(&env, &second);
+        record_game_joined(&env, &player, second.id);
+    });
+}
+
+#[test]
+fn test_leave_pending_game_marks_game_left_in_player_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let id = next_game_id(&env);
+        set_game(
+            &env,
+            &make_game_with_stake(&env, id, creator.clone(), 0, &[player2.clone()]),
+        );
+        record_game_joined(&env, &creator, id);
+        record_game_joined(&env, &player2, id);
+    });
+
+    client.leave_pending_game(&1, &player2);
+
+    let games = client.get_player_games(&player2, &0, &10);
+    assert_eq!(games.len(), 1);
+    assert_eq!(games.get(0).unwrap().game_id, 1);
+    assert!(games.get(0).unwrap().left);
+    assert_eq!(client.active_game_of(&player2), None);
+
+    let games = client.get_player_games(&creator, &0, &10);
+    assert_eq!(games.len(), 1);
+    assert!(!games.get(0).unwrap().left);
+    assert_eq!(client.active_game_of(&creator), Some(1));
+}
+
+#[test]
+fn test_rejoining_game_clears_left_marker() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let id = next_game_id(&env);
+        set_game(
+            &env,
+            &make_game_with_stake(&env, id, creator.clone(), 0, &[player2.clone()]),
+        );
+        record_game_joined(&env, &player2, id);
+    });
+
+    client.leave_pending_game(&1, &player2);
+    env.as_contract(&contract_id, || record_game_joined(&env, &player2, 1));
+
+    let games = client.get_player_games(&player2, &0, &10);
+    assert_eq!(games.len(), 1);
+    assert!(!games.get(0).unwrap().left);
+    assert_eq!(client.active_game_of(&player2), Some(1));
+}
+
+// -----------------------------------------------------------------------
+// Join codes
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_assign_game_code_is_unique_and_indexed() {
+    let env = Env::default();
+    let (contract_id, _, _, _, _) = setup_contract(&env);
+
+    env.as_contract(&contract_id, || {
+        let code1 = assign_game_code(&env, 1);
+        let code2 = assign_game_code(&env, 2);
+
+        assert_eq!(code1.len() as usize, CODE_LENGTH);
+        assert_ne!(code1, code2);
+        assert_eq!(get_game_id_by_code(&env, &code1), Some(1));
+        assert_eq!(get_game_id_by_code(&env, &code2), Some(2));
+    });
+}
+
+#[test]
+fn test_get_game_by_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let code = seed_game_with_code(&env, &contract_id, &creator, 0);
+
+    let game = client.get_game_by_code(&code).expect("Game not returned");
+    assert_eq!(game.id, 1);
+    assert_eq!(game.code, code);
+    assert!(client
+        .get_game_by_code(&String::from_str(&env, "NOPE99"))
+        .is_none());
+}
+
+#[test]
+fn test_join_game_by_code_adds_player_and_collects_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let stake: u128 = 300;
+    StellarAssetClient::new(&env, &usdc_token).mint(&player2, &(stake as i128));
+
+    let code = seed_game_with_code(&env, &contract_id, &creator, stake);
+
+    let game_id = client.join_game_by_code(&player2, &code);
+    assert_eq!(game_id, 1);
+    assert!(!env.events().all().is_empty());
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.joined_players.len(), 2);
+    assert_eq!(game.joined_players.get(1), Some(player2.clone()));
+    assert_eq!(game.total_staked, stake * 2);
+
+    let usdc = TokenClient::new(&env, &usdc_token);
+    assert_eq!(usdc.balance(&player2), 0);
+    assert_eq!(usdc.balance(&contract_id), stake as i128);
+
+    assert_eq!(client.active_game_of(&player2), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Invalid game code")]
+fn test_join_game_by_code_unknown_code_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.join_game_by_code(&Address::generate(&env), &String::from_str(&env, "NOPE99"));
+}
+
+#[test]
+#[should_panic(expected = "Game is full")]
+fn test_join_game_by_code_full_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let code = seed_game_with_code(&env, &contract_id, &creator, 0);
+
+    // make_game_with_stake uses 4 player slots; the creator holds one
+    for _ in 0..3 {
+        client.join_game_by_code(&Address::generate(&env), &code);
+    }
+    client.join_game_by_code(&Address::generate(&env), &code);
+}
+
+#[test]
+#[should_panic(expected = "Player already in this game")]
+fn test_join_game_by_code_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let code = seed_game_with_code(&env, &contract_id, &creator, 0);
+
+    client.join_game_by_code(&player2, &code);
+    client.join_game_by_code(&player2, &code);
+}
+
+#[test]
+#[should_panic(expected = "Player already in an active game")]
+fn test_join_game_by_code_while_in_other_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator1 = Address::generate(&env);
+    let creator2 = Address::generate(&env);
+    let player = Address::generate(&env);
+    let code1 = seed_game_with_code(&env, &contract_id, &creator1, 0);
+    let code2 = seed_game_with_code(&env, &contract_id, &creator2, 0);
+
+    client.join_game_by_code(&player, &code1);
+    client.join_game_by_code(&player, &code2);
+}
+
+#[test]
+fn test_join_code_released_when_lobby_empties() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let code = seed_game_with_code(&env, &contract_id, &creator, 0);
+
+    client.leave_pending_game(&1, &creator);
+
+    assert_eq!(client.get_game(&1).unwrap().status, GameStatus::Ended);
+    assert!(client.get_game_by_code(&code).is_none());
+    env.as_contract(&contract_id, || {
+        assert_eq!(get_game_id_by_code(&env, &code), None);
+    });
+}
+
+/// Seed a pending game whose code is not in the code index and return its id.
+fn seed_game_without_code(env: &Env, contract_id: &Address, creator: &Address) -> u64 {
+    env.as_contract(contract_id, || {
+        let id = next_game_id(env);
+        let game = make_game_with_stake(env, id, creator.clone(), 0, &[]);
+        set_game(env, &game);
+        record_game_joined(env, creator, id);
+        id
+    })
+}
+
+#[test]
+fn test_assign_code_lets_players_join_by_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let game_id = seed_game_without_code(&env, &contract_id, &creator);
+
+    let code = client.assign_code(&owner, &game_id);
+    assert_eq!(code.len() as usize, CODE_LENGTH);
+    assert_eq!(client.get_game(&game_id).unwrap().code, code);
+    assert_eq!(client.get_game_by_code(&code).unwrap().id, game_id);
+
+    let player2 = Address::generate(&env);
+    assert_eq!(client.join_game_by_code(&player2, &code), game_id);
+    assert_eq!(client.get_game(&game_id).unwrap().joined_players.len(), 2);
+}
+
+#[test]
+fn test_assign_code_by_backend_controller_replaces_old_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+
+    let creator = Address::generate(&env);
+    let old_code = seed_game_with_code(&env, &contract_id, &creator, 0);
+
+    let new_code = client.assign_code(&controller, &1);
+    assert_ne!(new_code, old_code);
+    assert!(client.get_game_by_code(&old_code).is_none());
+    assert_eq!(client.get_game_by_code(&new_code).unwrap().id, 1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+fn test_assign_code_by_stranger_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let game_id = seed_game_without_code(&env, &contract_id, &creator);
+
+    client.assign_code(&creator, &game_id);
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_assign_code_for_ongoing_game_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let game_id = seed_game_without_code(&env, &contract_id, &creator);
+    env.as_contract(&contract_id, || {
+        let mut game = get_game(&env, game_id).unwrap();
+        game.status = GameStatus::Ongoing;
+        set_game(&env, &game);
+    });
+
+    client.assign_code(&owner, &game_id);
+}
+
+#[test]
+#[should_panic(expected = "Invalid game code")]
+fn test_join_game_by_code_with_mismatched_index_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    let game_id = seed_game_without_code(&env, &contract_id, &creator);
+    // Index points at the game, but the game carries a different code
+    let code = env.as_contract(&contract_id, || assign_game_code(&env, game_id));
+
+    client.join_game_by_code(&Address::generate(&env), &code);
+}
+
+// -----------------------------------------------------------------------
+// cancel_pending_game
+// -----------------------------------------------------------------------
+
+/// Seed a pending game (id 1) with `stake` per player and fund the contract
+/// with every player's stake. Returns (creator, other players).
+fn seed_staked_lobby(
+    env: &Env,
+    contract_id: &Address,
+    usdc_token: &Address,
+    stake: u128,
+) -> (Address, Address, Address) {
+    let creator = Address::generate(env);
+    let player2 = Address::generate(env);
+    let player3 = Address::generate(env);
+
+    StellarAssetClient::new(env, usdc_token).mint(contract_id, &(stake as i128 * 3));
+
+    env.as_contract(contract_id, || {
+        let id = next_game_id(env);
+        let mut game = make_game_with_stake(
+            env,
+            id,
+            creator.clone(),
+            stake,
+            &[player2.clone(), player3.clone()],
+        );
+        game.code = assign_game_code(env, id);
+        set_game(env, &game);
+        for p in game.joined_players.iter() {
+            record_game_joined(env, &p, id);
+        }
+    });
+
+    (creator, player2, player3)
+}
+
+#[test]
+fn test_cancel_pending_game_by_creator_refunds_everyone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let stake: u128 = 250;
+    let (creator, player2, player3) = seed_staked_lobby(&env, &contract_id, &usdc_token, stake);
+    let code = client.get_game(&1).unwrap().code;
+
+    client.cancel_pending_game(&1, &creator);
+    assert!(!env.events().all().is_empty());
+
+    let usdc = TokenClient::new(&env, &usdc_token);
+    assert_eq!(usdc.balance(&creator), stake as i128);
+    assert_eq!(usdc.balance(&player2), stake as i128);
+    assert_eq!(usdc.balance(&player3), stake as i128);
+    assert_eq!(usdc.balance(&contract_id), 0);
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.status, GameStatus::Ended);
+    assert_eq!(game.total_staked, 0);
+    assert!(client.get_game_by_code(&code).is_none());
+    assert_eq!(client.active_game_of(&player2), None);
+}
+
+#[test]
+fn test_cancel_pending_game_by_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.cancel_pending_game(&1, &owner);
+    assert_eq!(client.get_game(&1).unwrap().status, GameStatus::Ended);
+}
+
+#[test]
+fn test_cancel_pending_game_by_backend_controller() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+    assert_eq!(
+        client.get_backend_game_controller(),
+        Some(controller.clone())
+    );
+
+    seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.cancel_pending_game(&1, &controller);
+    assert_eq!(client.get_game(&1).unwrap().status, GameStatus::Ended);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller cannot cancel this game")]
+fn test_cancel_pending_game_by_player_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (_, player2, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.cancel_pending_game(&1, &player2);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller cannot cancel this game")]
+fn test_cancel_pending_game_before_ttl_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_pending_game_ttl(&3_600);
+
+    seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    // make_game_with_stake creates games at timestamp 1_000
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.cancel_pending_game(&1, &Address::generate(&env));
+}
+
+#[test]
+fn test_cancel_expired_pending_game_is_permissionless() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    client.set_pending_game_ttl(&3_600);
+    assert_eq!(client.get_pending_game_ttl(), 3_600);
+
+    let stake: u128 = 100;
+    let (creator, _, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, stake);
+
+    env.ledger().set_timestamp(1_000 + 3_601);
+    client.cancel_pending_game(&1, &Address::generate(&env));
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.status, GameStatus::Ended);
+    assert_eq!(game.ended_at, 1_000 + 3_601);
+    assert_eq!(
+        TokenClient::new(&env, &usdc_token).balance(&creator),
+        stake as i128
+    );
+}
+
+#[test]
+#[should_panic(expected = "Game is not pending")]
+fn test_cancel_pending_game_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, _, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.cancel_pending_game(&1, &creator);
+    client.cancel_pending_game(&1, &creator);
+}
+
+// -----------------------------------------------------------------------
+// kick_from_pending
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_kick_from_pending_refunds_and_removes_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let stake: u128 = 400;
+    let (creator, player2, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, stake);
+
+    client.kick_from_pending(&1, &creator, &player2, &false);
+    assert!(!env.events().all().is_empty());
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.joined_players.len(), 2);
+    assert!(!game.joined_players.contains(&player2));
+    assert_eq!(game.total_staked, stake * 2);
+    assert_eq!(
+        TokenClient::new(&env, &usdc_token).balance(&player2),
+        stake as i128
+    );
+    assert_eq!(client.active_game_of(&player2), None);
+    assert!(!client.is_banned_from_game(&1, &player2));
+
+    // Not banned, so the player may rejoin using the refunded stake
+    client.join_game_by_code(&player2, &game.code);
+    assert_eq!(client.get_game(&1).unwrap().joined_players.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Player is banned from this game")]
+fn test_kick_from_pending_with_ban_blocks_rejoin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, player2, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+    let code = client.get_game(&1).unwrap().code;
+
+    client.kick_from_pending(&1, &creator, &player2, &true);
+    assert!(client.is_banned_from_game(&1, &player2));
+
+    client.join_game_by_code(&player2, &code);
+}
+
+#[test]
+#[should_panic(expected = "Cannot kick the creator")]
+fn test_kick_from_pending_creator_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, _, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.kick_from_pending(&1, &creator, &creator, &false);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only the creator can kick players")]
+fn test_kick_from_pending_non_creator_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (_, player2, player3) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.kick_from_pending(&1, &player2, &player3, &false);
+}
+
+#[test]
+#[should_panic(expected = "Player is not in this game")]
+fn test_kick_from_pending_non_member_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, _, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.kick_from_pending(&1, &creator, &Address::generate(&env), &false);
+}
+
+// -----------------------------------------------------------------------
+// Creator handover
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_creator_leaving_hands_over_to_next_player() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, player2, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.leave_pending_game(&1, &creator);
+    // PlayerLeftPending + CreatorChanged
+    assert!(env.events().all().len() >= 2);
+
+    let game = client.get_game(&1).unwrap();
+    assert_eq!(game.creator, player2);
+    assert_eq!(game.joined_players.get(0), Some(player2));
+    assert_eq!(game.status, GameStatus::Pending);
+}
+
+#[test]
+fn test_new_creator_can_kick_and_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, player2, player3) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.leave_pending_game(&1, &creator);
+    client.kick_from_pending(&1, &player2, &player3, &false);
+    client.cancel_pending_game(&1, &player2);
+
+    assert_eq!(client.get_game(&1).unwrap().status, GameStatus::Ended);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only the creator can kick players")]
+fn test_former_creator_cannot_kick_after_leaving() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, _, player3) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.leave_pending_game(&1, &creator);
+    client.kick_from_pending(&1, &creator, &player3, &false);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller cannot cancel this game")]
+fn test_former_creator_cannot_cancel_after_leaving() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let (creator, _, _) = seed_staked_lobby(&env, &contract_id, &usdc_token, 0);
+
+    client.leave_pending_game(&1, &creator);
+    client.cancel_pending_game(&1, &creator);
+}
+
+// -----------------------------------------------------------------------
+// Two-step ownership
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_transfer_ownership_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let new_owner = Address::generate(&env);
+    client.transfer_ownership(&new_owner);
+
+    let (emitter, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(emitter, contract_id);
+    let expected: Vec<Val> = (
+        Symbol::new(&env, "OwnershipTransferStarted"),
+        owner.clone(),
+        new_owner.clone(),
+    )
+        .into_val(&env);
+    assert_eq!(topics, expected);
+
+    assert_eq!(client.get_owner(), owner);
+    assert_eq!(client.pending_owner(), Some(new_owner.clone()));
+
+    client.accept_ownership();
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> =
+        (Symbol::new(&env, "OwnershipTransferred"), owner.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+
+    assert_eq!(client.get_owner(), new_owner);
+    assert_eq!(client.pending_owner(), None);
+
+    // Owner-only calls are now authorized by the new owner
+    client.set_pending_game_ttl(&60);
+    assert_eq!(env.auths()[0].0, new_owner);
+}
+
+#[test]
+fn test_cancel_ownership_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.transfer_ownership(&Address::generate(&env));
+    client.cancel_ownership_transfer();
+
+    assert_eq!(client.pending_owner(), None);
+    assert!(client.try_accept_ownership().is_err());
+    assert_eq!(client.get_owner(), owner);
+}
+
+#[test]
+#[should_panic(expected = "Owner not set")]
+fn test_renounce_ownership_disables_owner_functions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    client.renounce_ownership();
+    client.set_pending_game_ttl(&60);
+}
+
+// -----------------------------------------------------------------------
+// Staked lobbies
+// -----------------------------------------------------------------------
+
+const STAKING_TERM: u64 = 30 * 24 * 60 * 60;
+
+/// Seeds a game that requires `min_staked` TYC staked to join.
+fn seed_staked_game(
+    env: &Env,
+    contract_id: &Address,
+    creator: &Address,
+    min_staked: i128,
+) -> String {
+    let code = seed_game_with_code(env, contract_id, creator, 0);
+    env.as_contract(contract_id, || {
+        let game_id = get_game_id_by_code(env, &code).unwrap();
+        set_min_staked(env, game_id, min_staked);
+    });
+    code
+}
+
+/// Deploys a staking contract offering a reward-free term and stakes
+/// `amount` TYC for `player`.
+fn setup_staking(env: &Env, player: &Address, amount: i128) -> Address {
+    let tyc_admin = Address::generate(env);
+    let tyc = env.register_stellar_asset_contract_v2(tyc_admin).address();
+
+    let staking_id = env.register(TycoonStaking, ());
+    let staking = TycoonStakingClient::new(env, &staking_id);
+    staking.initialize(&Address::generate(env), &tyc);
+    staking.set_term(&STAKING_TERM, &0);
+
+    if amount > 0 {
+        StellarAssetClient::new(env, &tyc).mint(player, &amount);
+        staking.stake(player, &amount, &STAKING_TERM);
+    }
+    staking_id
+}
+
+#[test]
+fn test_set_staking_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    assert_eq!(client.get_staking_contract(), None);
+
+    let staking = Address::generate(&env);
+    client.set_staking_contract(&staking);
+
+    assert_eq!(env.auths()[0].0, owner);
+    assert_eq!(client.get_staking_contract(), Some(staking));
+}
+
+#[test]
+fn test_set_min_staked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let controller = Address::generate(&env);
+    client.set_backend_game_controller(&controller);
+    let code = seed_game_with_code(&env, &contract_id, &Address::generate(&env), 0);
+    assert_eq!(client.get_min_staked(&1), 0);
+
+    client.set_min_staked(&owner, &1, &1_000);
+    assert_eq!(client.get_min_staked(&1), 1_000);
+
+    // Removing the requirement lets players without a stake join again
+    client.set_min_staked(&controller, &1, &0);
+    assert_eq!(client.get_min_staked(&1), 0);
+    client.join_game_by_code(&Address::generate(&env), &code);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+fn test_set_min_staked_by_stranger_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let creator = Address::generate(&env);
+    seed_game_with_code(&env, &contract_id, &creator, 0);
+
+    client.set_min_staked(&creator, &1, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Minimum stake cannot be negative")]
+fn test_set_min_staked_negative_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+    seed_game_with_code(&env, &contract_id, &Address::generate(&env), 0);
+
+    client.set_min_staked(&owner, &1, &-1);
+}
+
+#[test]
+fn test_join_staked_lobby_with_enough_staked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = Address::generate(&env);
+    client.set_staking_contract(&setup_staking(&env, &player, 1_000));
+    let code = seed_staked_game(&env, &contract_id, &Address::generate(&env), 1_000);
+
+    assert_eq!(client.join_game_by_code(&player, &code), 1);
+    assert_eq!(client.active_game_of(&player), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient staked balance")]
+fn test_join_staked_lobby_with_too_little_staked_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let player = Address::generate(&env);
+    client.set_staking_contract(&setup_staking(&env, &player, 999));
+    let code = seed_staked_game(&env, &contract_id, &Address::generate(&env), 1_000);
+
+    client.join_game_by_code(&player, &code);
+}
+
+#[test]
+#[should_panic(expected = "Staking contract not set")]
+fn test_join_staked_lobby_without_staking_contract_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, owner, reward_system, usdc_token) = setup_contract(&env);
+    client.initialize(&owner, &reward_system, &usdc_token);
+
+    let code = seed_staked_game(&env, &contract_id, &Address::generate(&env), 1_000);
+
+    client.join_game_by_code(&Address::generate(&env), &code);
+}
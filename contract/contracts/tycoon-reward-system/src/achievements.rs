@@ -0,0 +1,237 @@
+use crate::{
+    DataKey, PayoutKind, TycoonRewardSystem, TycoonRewardSystemArgs, TycoonRewardSystemClient,
+};
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env};
+use tycoon_lib::ownership;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// An admin-defined achievement and the TYC voucher it rewards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Achievement {
+    /// TYC value of the voucher before the streak bonus.
+    pub reward_value: u128,
+    /// Whether a player can claim the achievement more than once.
+    pub repeatable: bool,
+}
+
+/// A processed claim, stored under its idempotency key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AchievementClaim {
+    pub player: Address,
+    pub achievement_id: u32,
+    pub token_id: u128,
+}
+
+#[contractimpl]
+impl TycoonRewardSystem {
+    /// Create or update an achievement (admin only)
+    ///
+    /// # Panics
+    /// * If `reward_value` is zero
+    pub fn set_achievement(e: Env, achievement_id: u32, reward_value: u128, repeatable: bool) {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        if reward_value == 0 {
+            panic!("Reward must be positive");
+        }
+        e.storage().persistent().set(
+            &DataKey::Achievement(achievement_id),
+            &Achievement {
+                reward_value,
+                repeatable,
+            },
+        );
+        #[allow(deprecated)]
+        e.events().publish(
+            (symbol_short!("Ach_Set"), achievement_id),
+            (reward_value, repeatable),
+        );
+    }
+
+    /// Stop offering an achievement (admin only). Claims already made are kept.
+    pub fn remove_achievement(e: Env, achievement_id: u32) {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        e.storage()
+            .persistent()
+            .remove(&DataKey::Achievement(achievement_id));
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Ach_Rm"), achievement_id), ());
+    }
+
+    /// Get an achievement, if defined
+    pub fn get_achievement(e: Env, achievement_id: u32) -> Option<Achievement> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Achievement(achievement_id))
+    }
+
+    /// Set how the login streak scales achievement rewards (admin only)
+    ///
+    /// Every streak day after the first adds `bonus_bps` to the reward, up to
+    /// `max_bonus_bps` in total.
+    pub fn set_streak_bonus(e: Env, bonus_bps: u32, max_bonus_bps: u32) {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        e.storage()
+            .instance()
+            .set(&DataKey::StreakBonus, &(bonus_bps, max_bonus_bps));
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Strk_Set"),), (bonus_bps, max_bonus_bps));
+    }
+
+    /// Get the streak bonus as (bonus per streak day, maximum bonus) in basis
+    /// points
+    pub fn get_streak_bonus(e: Env) -> (u32, u32) {
+        e.storage()
+            .instance()
+            .get(&DataKey::StreakBonus)
+            .unwrap_or((0, 0))
+    }
+
+    /// Record a player's daily login (admin or backend minter)
+    ///
+    /// Logging in on the day after the last recorded login extends the
+    /// streak, a later login starts a new one and further logins on the same
+    /// day change nothing.
+    ///
+    /// # Returns
+    /// The player's streak length in days
+    pub fn record_login(e: Env, caller: Address, player: Address) -> u32 {
+        Self::require_voucher_minter(&e, &caller);
+
+        let today = e.ledger().timestamp() / SECONDS_PER_DAY;
+        let key = DataKey::LoginStreak(player.clone());
+        let (streak, last_day): (u32, u64) = e.storage().persistent().get(&key).unwrap_or((0, 0));
+        let streak = if streak > 0 && last_day == today {
+            return streak;
+        } else if streak > 0 && last_day + 1 == today {
+            streak.saturating_add(1)
+        } else {
+            1
+        };
+        e.storage().persistent().set(&key, &(streak, today));
+
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("Login"), player), streak);
+        streak
+    }
+
+    /// Get a player's current login streak in days. A streak whose last
+    /// login was before yesterday is broken and reads as 0.
+    pub fn get_streak(e: Env, player: Address) -> u32 {
+        Self::current_streak(&e, &player)
+    }
+
+    /// Reward a player for an achievement with a TYC voucher (admin or backend
+    /// minter)
+    ///
+    /// The voucher is worth the achievement's reward plus the player's streak
+    /// bonus. `idempotency_key` identifies the claim: repeating a processed
+    /// claim returns its voucher id without minting again.
+    ///
+    /// # Returns
+    /// The id of the minted voucher
+    ///
+    /// # Panics
+    /// * If caller is neither admin nor backend minter
+    /// * If the achievement does not exist
+    /// * If a one-time achievement was already claimed by the player
+    /// * If `idempotency_key` was used for a different claim
+    /// * If a liability overdraft is set and the voucher would exceed it
+    pub fn claim_achievement(
+        e: Env,
+        caller: Address,
+        player: Address,
+        achievement_id: u32,
+        idempotency_key: BytesN<32>,
+    ) -> u128 {
+        Self::require_voucher_minter(&e, &caller);
+
+        let claim_key = DataKey::AchievementClaim(idempotency_key);
+        if let Some(claim) = e
+            .storage()
+            .persistent()
+            .get::<_, AchievementClaim>(&claim_key)
+        {
+            if claim.player != player || claim.achievement_id != achievement_id {
+                panic!("Idempotency key already used");
+            }
+            return claim.token_id;
+        }
+
+        let achievement: Achievement = e
+            .storage()
+            .persistent()
+            .get(&DataKey::Achievement(achievement_id))
+            .expect("Achievement not found");
+        if !achievement.repeatable {
+            let claimed_key = DataKey::AchievementClaimed(player.clone(), achievement_id);
+            if e.storage().persistent().has(&claimed_key) {
+                panic!("Achievement already claimed");
+            }
+            e.storage().persistent().set(&claimed_key, &true);
+        }
+
+        let reward = Self::streak_reward(&e, &player, achievement.reward_value);
+        let token_id = Self::create_voucher(&e, &player, reward, None, &PayoutKind::Tyc, true);
+        Self::check_solvency(&e);
+
+        e.storage().persistent().set(
+            &claim_key,
+            &AchievementClaim {
+                player: player.clone(),
+                achievement_id,
+                token_id,
+            },
+        );
+
+        #[allow(deprecated)]
+        e.events().publish(
+            (symbol_short!("Ach_Claim"), player, achievement_id),
+            (token_id, reward),
+        );
+        token_id
+    }
+
+    /// Whether a player has claimed a one-time achievement
+    pub fn has_claimed_achievement(e: Env, player: Address, achievement_id: u32) -> bool {
+        e.storage()
+            .persistent()
+            .has(&DataKey::AchievementClaimed(player, achievement_id))
+    }
+}
+
+impl TycoonRewardSystem {
+    fn current_streak(e: &Env, player: &Address) -> u32 {
+        let today = e.ledger().timestamp() / SECONDS_PER_DAY;
+        let (streak, last_day): (u32, u64) = e
+            .storage()
+            .persistent()
+            .get(&DataKey::LoginStreak(player.clone()))
+            .unwrap_or((0, 0));
+        if last_day + 1 >= today {
+            streak
+        } else {
+            0
+        }
+    }
+
+    /// Scales `reward_value` by the player's streak bonus.
+    fn streak_reward(e: &Env, player: &Address, reward_value: u128) -> u128 {
+        let (bonus_bps, max_bonus_bps) = Self::get_streak_bonus(e.clone());
+        let streak_days = Self::current_streak(e, player).saturating_sub(1);
+        let bonus_bps = bonus_bps.saturating_mul(streak_days).min(max_bonus_bps);
+        let bonus = reward_value
+            .checked_mul(bonus_bps as u128)
+            .expect("Reward overflow")
+            / BPS_DENOMINATOR;
+        reward_value.checked_add(bonus).expect("Reward overflow")
+    }
+}
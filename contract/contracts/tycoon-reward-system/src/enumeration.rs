@@ -0,0 +1,68 @@
+use crate::DataKey;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Add a voucher to an address's owned vouchers list using indexed storage
+/// Only call this when balance transitions from 0 to > 0
+pub fn add_token_to_owner(e: &Env, owner: &Address, token_id: u128) {
+    let index_key = DataKey::OwnedTokenIndex(owner.clone(), token_id);
+    if e.storage().persistent().has(&index_key) {
+        return;
+    }
+
+    let mut tokens = get_owned_tokens(e, owner);
+    let new_index = tokens.len();
+    tokens.push_back(token_id);
+
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnedTokens(owner.clone()), &tokens);
+    e.storage().persistent().set(&index_key, &new_index);
+}
+
+/// Remove a voucher from an address's owned vouchers list using swap-remove
+/// Only call this when balance transitions to 0
+pub fn remove_token_from_owner(e: &Env, owner: &Address, token_id: u128) {
+    let index_key = DataKey::OwnedTokenIndex(owner.clone(), token_id);
+    let Some(token_index) = e.storage().persistent().get::<_, u32>(&index_key) else {
+        return;
+    };
+
+    let mut tokens = get_owned_tokens(e, owner);
+    if tokens.is_empty() {
+        return;
+    }
+    let last_index = tokens.len() - 1;
+
+    // Move the last voucher into the freed slot and update its index
+    if token_index != last_index {
+        let last_token_id = tokens.get(last_index).unwrap();
+        tokens.set(token_index, last_token_id);
+        e.storage().persistent().set(
+            &DataKey::OwnedTokenIndex(owner.clone(), last_token_id),
+            &token_index,
+        );
+    }
+    tokens.pop_back();
+    e.storage().persistent().remove(&index_key);
+
+    let tokens_key = DataKey::OwnedTokens(owner.clone());
+    if tokens.is_empty() {
+        e.storage().persistent().remove(&tokens_key);
+    } else {
+        e.storage().persistent().set(&tokens_key, &tokens);
+    }
+}
+
+/// Get all vouchers owned by an address
+pub fn get_owned_tokens(e: &Env, owner: &Address) -> Vec<u128> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::OwnedTokens(owner.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Get the voucher ID at a specific index for an owner
+/// Returns None if index is out of bounds
+pub fn token_of_owner_by_index(e: &Env, owner: &Address, index: u32) -> Option<u128> {
+    get_owned_tokens(e, owner).get(index)
+}
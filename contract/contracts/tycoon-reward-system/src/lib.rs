@@ -1,8 +1,41 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol,
+    Val, Vec,
+};
+use tycoon_lib::ownership;
+
+mod achievements;
+mod enumeration;
+
+pub use achievements::{Achievement, AchievementClaim};
 
 const VOUCHER_ID_START: u128 = 1_000_000_000;
 
+// Configuration lives in instance storage, kept alive for about 30 days
+// after each state-changing call
+const DAY_IN_LEDGERS: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Maximum number of vouchers handled by a single batch call.
+pub const MAX_VOUCHER_BATCH: u32 = 100;
+
+/// What a voucher pays out when redeemed. The voucher's value is the amount
+/// of TYC or USDC, or the number of copies of the collectible.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutKind {
+    Tyc,
+    Usdc,
+    // Token id on the tycoon-collectibles contract
+    Collectible(u128),
+}
+
+/// Contract configuration (`TycToken`, `UsdcToken`, `VoucherCount`, `Paused`,
+/// `BackendMinter`, `CollectiblesContract`, `OutstandingLiability`,
+/// `LiabilityOverdraft`, `StreakBonus`) is kept in instance storage; all other
+/// keys are persistent.
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -10,6 +43,14 @@ pub enum DataKey {
     Balance(Address, u128),
     // TokenID -> Value
     VoucherValue(u128),
+    // TokenID -> Ledger timestamp from which the voucher can no longer be redeemed
+    VoucherExpiry(u128),
+    // TokenID -> Current holder of the voucher
+    VoucherOwner(u128),
+    // TokenID -> PayoutKind (TYC when unset)
+    VoucherPayout(u128),
+    // TokenID -> true for vouchers that cannot be transferred (transferable when unset)
+    VoucherSoulbound(u128),
     // TokenID -> Perk Enum (u32)
     CollectiblePerk(u128),
     // TokenID -> Strength
@@ -17,15 +58,46 @@ pub enum DataKey {
     // TokenID -> Price
     CollectibleTyc(u128),
     CollectibleUsdc(u128),
+    // Admin written by releases before tycoon_lib::ownership; only read when migrating it
     Admin,
     TycToken,
     UsdcToken,
+    // tycoon-collectibles contract paying out collectible vouchers
+    CollectiblesContract,
+    // Sum of the values of unredeemed TYC vouchers
+    OutstandingLiability,
+    // TYC liability allowed above the contract's balance when minting (unchecked when unset)
+    LiabilityOverdraft,
     VoucherCount,
     Paused,
     // Backend minter address (optional - None if not set)
     BackendMinter,
     // (Owner) -> Total distinct vouchers owned
     OwnedTokenCount(Address),
+    // (Owner) -> Vec of owned token IDs
+    OwnedTokens(Address),
+    // (Owner, TokenID) -> Position in the owner's OwnedTokens
+    OwnedTokenIndex(Address, u128),
+    // AchievementID -> Achievement
+    Achievement(u32),
+    // (Player, AchievementID) -> Whether a one-time achievement was claimed
+    AchievementClaimed(Address, u32),
+    // Idempotency key -> AchievementClaim
+    AchievementClaim(BytesN<32>),
+    // Player -> (Streak length, Day of the last recorded login)
+    LoginStreak(Address),
+    // (Bonus per streak day, Maximum bonus) in basis points
+    StreakBonus,
+}
+
+/// A voucher's redemption terms, as returned by `get_voucher`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherInfo {
+    pub value: u128,
+    pub expires_at: Option<u64>,
+    pub payout: PayoutKind,
+    pub transferable: bool,
 }
 
 #[contract]
@@ -39,30 +111,48 @@ pub struct TycoonRewardSystem;
 /// Events are emitted for Paused/Unpaused. This mechanism is for emergency use only.
 impl TycoonRewardSystem {
     pub fn initialize(e: Env, admin: Address, tyc_token: Address, usdc_token: Address) {
-        if e.storage().persistent().has(&DataKey::Admin) {
+        // Deployments from before instance storage keep Admin and TycToken in
+        // persistent storage until migrate_config runs
+        if ownership::is_owner_set(&e)
+            || e.storage().persistent().has(&DataKey::Admin)
+            || e.storage().persistent().has(&DataKey::TycToken)
+        {
             panic!("Already initialized");
         }
-        e.storage().persistent().set(&DataKey::Admin, &admin);
-        e.storage().persistent().set(&DataKey::TycToken, &tyc_token);
-        e.storage()
-            .persistent()
-            .set(&DataKey::UsdcToken, &usdc_token);
+        admin.require_auth();
+        ownership::init_owner(&e, &admin);
+        e.storage().instance().set(&DataKey::TycToken, &tyc_token);
+        e.storage().instance().set(&DataKey::UsdcToken, &usdc_token);
         e.storage()
-            .persistent()
+            .instance()
             .set(&DataKey::VoucherCount, &VOUCHER_ID_START);
-        e.storage().persistent().set(&DataKey::Paused, &false);
+        e.storage().instance().set(&DataKey::Paused, &false);
+        Self::extend_instance_ttl(&e);
+    }
+
+    /// Move configuration written to persistent storage by earlier versions of
+    /// the contract into instance storage (admin only)
+    ///
+    /// On deployments that predate tycoon_lib::ownership the legacy admin is
+    /// migrated first (see `migrate_admin`) and must authorize the call.
+    /// Safe to call more than once: keys already migrated are skipped.
+    ///
+    /// # Returns
+    /// The number of keys migrated, not counting the admin
+    pub fn migrate_config(e: Env) -> u32 {
+        if !ownership::is_owner_set(&e) {
+            Self::adopt_legacy_admin(&e);
+        }
+        ownership::require_owner(&e);
+        Self::move_legacy_config(&e)
     }
 
     /// Emergency pause contract (admin only)
     /// Use in case of vulnerability or exploit. Pauses redeem functionality.
     pub fn pause(e: Env) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
-        e.storage().persistent().set(&DataKey::Paused, &true);
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        e.storage().instance().set(&DataKey::Paused, &true);
         #[allow(deprecated)]
         e.events().publish((symbol_short!("Paused"),), true);
     }
@@ -70,13 +160,9 @@ impl TycoonRewardSystem {
     /// Emergency unpause contract (admin only)
     /// Use to resume normal operation after emergency.
     pub fn unpause(e: Env) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
-        e.storage().persistent().set(&DataKey::Paused, &false);
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        e.storage().instance().set(&DataKey::Paused, &false);
         #[allow(deprecated)]
         e.events().publish((symbol_short!("Unpaused"),), false);
     }
@@ -91,21 +177,18 @@ impl TycoonRewardSystem {
     /// * If caller is not admin
     pub fn set_backend_minter(e: Env, admin: Address, new_minter: Address) {
         // Get stored admin for validation
-        let stored_admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
+        let stored_admin = ownership::get_owner(&e);
 
         // Verify caller is admin
         if admin != stored_admin {
             panic!("Unauthorized: only admin can set backend minter");
         }
         admin.require_auth();
+        Self::extend_instance_ttl(&e);
 
         // Store the new minter
         e.storage()
-            .persistent()
+            .instance()
             .set(&DataKey::BackendMinter, &new_minter);
 
         // Emit event
@@ -123,20 +206,17 @@ impl TycoonRewardSystem {
     /// * If caller is not admin
     pub fn clear_backend_minter(e: Env, admin: Address) {
         // Get stored admin for validation
-        let stored_admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
+        let stored_admin = ownership::get_owner(&e);
 
         // Verify caller is admin
         if admin != stored_admin {
             panic!("Unauthorized: only admin can clear backend minter");
         }
         admin.require_auth();
+        Self::extend_instance_ttl(&e);
 
         // Remove the backend minter
-        e.storage().persistent().remove(&DataKey::BackendMinter);
+        e.storage().instance().remove(&DataKey::BackendMinter);
 
         // Emit event
         #[allow(deprecated)]
@@ -146,109 +226,347 @@ impl TycoonRewardSystem {
     /// Get the current backend minter address
     /// Returns None if not set
     pub fn get_backend_minter(e: Env) -> Option<Address> {
-        if e.storage().persistent().has(&DataKey::BackendMinter) {
-            Some(
-                e.storage()
-                    .persistent()
-                    .get(&DataKey::BackendMinter)
-                    .unwrap(),
-            )
+        if e.storage().instance().has(&DataKey::BackendMinter) {
+            Some(e.storage().instance().get(&DataKey::BackendMinter).unwrap())
         } else {
             None
         }
     }
 
-    pub fn mint_voucher(e: Env, caller: Address, to: Address, tyc_value: u128) -> u128 {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        caller.require_auth();
-
-        // Check if caller is admin or backend minter
-        let backend_minter: Option<Address> =
-            if e.storage().persistent().has(&DataKey::BackendMinter) {
-                Some(
-                    e.storage()
-                        .persistent()
-                        .get(&DataKey::BackendMinter)
-                        .unwrap(),
-                )
-            } else {
-                None
-            };
+    /// Set the tycoon-collectibles contract that pays out collectible
+    /// vouchers (admin only)
+    ///
+    /// This contract must be the collectibles contract's backend minter for
+    /// collectible vouchers to be redeemable.
+    pub fn set_collectibles_contract(e: Env, collectibles: Address) {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        e.storage()
+            .instance()
+            .set(&DataKey::CollectiblesContract, &collectibles);
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("set_coll"), collectibles), ());
+    }
 
-        let is_admin = caller == admin;
-        let is_backend_minter = backend_minter.is_some() && backend_minter.unwrap() == caller;
+    /// Get the tycoon-collectibles contract, if set
+    pub fn get_collectibles_contract(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::CollectiblesContract)
+    }
 
-        if !is_admin && !is_backend_minter {
-            panic!("Unauthorized: only admin or backend minter can mint");
+    /// Require TYC backing for new vouchers (admin only)
+    ///
+    /// With `Some(overdraft)`, minting fails when the outstanding TYC liability
+    /// would exceed the contract's TYC balance plus `overdraft`. `None` turns
+    /// the check off.
+    pub fn set_liability_overdraft(e: Env, overdraft: Option<u128>) {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        match overdraft {
+            Some(overdraft) => e
+                .storage()
+                .instance()
+                .set(&DataKey::LiabilityOverdraft, &overdraft),
+            None => e.storage().instance().remove(&DataKey::LiabilityOverdraft),
         }
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("set_ovd"),), overdraft);
+    }
 
-        let mut current_id: u128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::VoucherCount)
-            .unwrap_or(VOUCHER_ID_START);
-        let token_id = current_id;
-        current_id += 1;
-        e.storage()
-            .persistent()
-            .set(&DataKey::VoucherCount, &current_id);
+    /// Get the overdraft allowed when minting, None if minting is unchecked
+    pub fn get_liability_overdraft(e: Env) -> Option<u128> {
+        e.storage().instance().get(&DataKey::LiabilityOverdraft)
+    }
 
+    /// Set the outstanding TYC liability (admin only)
+    ///
+    /// Backfills the liability of deployments whose TYC vouchers were minted
+    /// before it was tracked: pass the total value of every unredeemed TYC
+    /// voucher. Releasing more than the recorded liability emits `Liab_Gap`.
+    pub fn set_outstanding_liability(e: Env, liability: u128) {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
         e.storage()
-            .persistent()
-            .set(&DataKey::VoucherValue(token_id), &tyc_value);
+            .instance()
+            .set(&DataKey::OutstandingLiability, &liability);
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("set_liab"),), liability);
+    }
+
+    /// Get the total value of unredeemed TYC vouchers
+    pub fn outstanding_liability(e: Env) -> u128 {
+        Self::liability(&e)
+    }
 
-        Self::_mint(&e, to.clone(), token_id, 1);
+    /// Whether the contract holds enough TYC to redeem every outstanding TYC
+    /// voucher
+    pub fn is_solvent(e: Env) -> bool {
+        Self::liability(&e) <= Self::tyc_balance(&e)
+    }
+
+    /// Mint a voucher worth `value` to `to` (admin or backend minter)
+    ///
+    /// # Arguments
+    /// * `value` - Amount of TYC or USDC, or number of collectible copies
+    /// * `expires_at` - Optional ledger timestamp from which the voucher can
+    ///   no longer be redeemed. `None` for vouchers that never expire.
+    /// * `payout` - What the voucher pays out when redeemed
+    /// * `transferable` - `false` mints a soulbound voucher that only its
+    ///   recipient can redeem
+    ///
+    /// # Panics
+    /// * If caller is neither admin nor backend minter
+    /// * If `expires_at` is not in the future
+    /// * If `payout` is a collectible and no collectibles contract is set, or
+    ///   `value` is zero or does not fit a collectible amount
+    /// * If a liability overdraft is set and the new TYC liability would
+    ///   exceed the contract's TYC balance plus the overdraft
+    pub fn mint_voucher(
+        e: Env,
+        caller: Address,
+        to: Address,
+        value: u128,
+        expires_at: Option<u64>,
+        payout: PayoutKind,
+        transferable: bool,
+    ) -> u128 {
+        Self::require_voucher_minter(&e, &caller);
+        Self::check_expiry(&e, expires_at);
+        Self::check_payout(&e, &payout, value);
+
+        let token_id = Self::create_voucher(&e, &to, value, expires_at, &payout, transferable);
+        Self::check_solvency(&e);
 
         #[allow(deprecated)]
         e.events()
-            .publish((symbol_short!("V_Mint"), to, token_id), tyc_value);
+            .publish((symbol_short!("V_Mint"), to, token_id), value);
 
         token_id
     }
 
+    /// Mint one voucher per recipient in a single call (admin or backend minter)
+    ///
+    /// Same authorization as `mint_voucher`. `values[i]` is the value of the
+    /// voucher minted to `recipients[i]`; all vouchers share `expires_at`,
+    /// `payout` and `transferable`.
+    /// Ids are assigned consecutively and emitted once in a `V_Batch` event.
+    ///
+    /// # Returns
+    /// The minted voucher ids, in recipient order
+    ///
+    /// # Panics
+    /// * If caller is neither admin nor backend minter
+    /// * If `recipients` is empty or longer than `MAX_VOUCHER_BATCH`
+    /// * If `recipients` and `values` differ in length
+    /// * If `expires_at` is not in the future
+    /// * If `payout` is invalid for any of `values` (see `mint_voucher`)
+    /// * If the batch breaks the liability overdraft (see `mint_voucher`)
+    pub fn mint_voucher_batch(
+        e: Env,
+        caller: Address,
+        recipients: Vec<Address>,
+        values: Vec<u128>,
+        expires_at: Option<u64>,
+        payout: PayoutKind,
+        transferable: bool,
+    ) -> Vec<u128> {
+        Self::require_voucher_minter(&e, &caller);
+        if recipients.is_empty() {
+            panic!("Empty batch");
+        }
+        if recipients.len() > MAX_VOUCHER_BATCH {
+            panic!("Batch too large");
+        }
+        if recipients.len() != values.len() {
+            panic!("Recipients and values length mismatch");
+        }
+        Self::check_expiry(&e, expires_at);
+
+        let mut token_ids = Vec::new(&e);
+        let mut total_value: u128 = 0;
+        for (to, value) in recipients.iter().zip(values.iter()) {
+            Self::check_payout(&e, &payout, value);
+            token_ids.push_back(Self::create_voucher(
+                &e,
+                &to,
+                value,
+                expires_at,
+                &payout,
+                transferable,
+            ));
+            total_value = total_value
+                .checked_add(value)
+                .expect("Total value overflow");
+        }
+        Self::check_solvency(&e);
+
+        #[allow(deprecated)]
+        e.events().publish(
+            (symbol_short!("V_Batch"), caller),
+            (token_ids.first().unwrap(), token_ids.len(), total_value),
+        );
+
+        token_ids
+    }
+
     pub fn redeem_voucher(_e: Env, _token_id: u128) {
         // Wrapper entry point that panics directing to redeem_voucher_from
         panic!("Use redeem_voucher_from instead");
     }
 
+    /// Redeem a voucher held by `redeemer`, paying out its value according to
+    /// its payout kind: TYC or USDC from this contract's balance, or
+    /// collectible copies minted on tycoon-collectibles.
     pub fn redeem_voucher_from(e: Env, redeemer: Address, token_id: u128) {
         redeemer.require_auth();
-        let paused: bool = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            panic!("Contract is paused");
-        }
-        let tyc_value: u128 = e
+        Self::extend_instance_ttl(&e);
+        Self::require_not_paused(&e);
+        let value: u128 = e
             .storage()
             .persistent()
             .get(&DataKey::VoucherValue(token_id))
             .expect("Invalid token_id");
+        if Self::is_expired(&e, token_id) {
+            panic!("Voucher expired");
+        }
+        let payout = Self::payout_of(&e, token_id);
         // Burn the voucher (amount=1)
         Self::_burn(&e, redeemer.clone(), token_id, 1);
-        // Transfer TYC
-        let tyc_token: Address = e
+        // Delete storage
+        Self::remove_voucher(&e, token_id, value);
+
+        let contract_address = e.current_contract_address();
+        match payout {
+            PayoutKind::Tyc | PayoutKind::Usdc => {
+                let token_key = if payout == PayoutKind::Tyc {
+                    DataKey::TycToken
+                } else {
+                    DataKey::UsdcToken
+                };
+                let token: Address = e
+                    .storage()
+                    .instance()
+                    .get(&token_key)
+                    .expect("Not initialized");
+                let client = soroban_sdk::token::Client::new(&e, &token);
+                // Transfer from Contract to Redeemer
+                client.transfer(&contract_address, &redeemer, &(value as i128));
+            }
+            PayoutKind::Collectible(collectible_id) => {
+                let collectibles: Address = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::CollectiblesContract)
+                    .expect("Collectibles contract not set");
+                // Mint the copies to the redeemer as the collectibles backend minter
+                e.invoke_contract::<()>(
+                    &collectibles,
+                    &Symbol::new(&e, "backend_mint"),
+                    vec![
+                        &e,
+                        contract_address.into_val(&e),
+                        redeemer.into_val(&e),
+                        collectible_id.into_val(&e),
+                        (value as u64).into_val(&e),
+                    ],
+                );
+            }
+        }
+
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Redeem"), redeemer, token_id), value);
+    }
+
+    /// Delete expired vouchers (admin only)
+    ///
+    /// Burns each expired voucher from its holder and removes its value and
+    /// expiry, emitting a `V_Expire` event per voucher so the backend can
+    /// reconcile. Ids that do not exist or have not expired are skipped.
+    ///
+    /// # Arguments
+    /// * `token_ids` - Candidate voucher ids, at most `MAX_VOUCHER_BATCH`
+    ///
+    /// # Returns
+    /// The number of vouchers deleted
+    pub fn sweep_expired(e: Env, token_ids: Vec<u128>) -> u32 {
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
+        if token_ids.len() > MAX_VOUCHER_BATCH {
+            panic!("Batch too large");
+        }
+
+        let mut swept = 0;
+        for token_id in token_ids.iter() {
+            let Some(value) = e
+                .storage()
+                .persistent()
+                .get::<_, u128>(&DataKey::VoucherValue(token_id))
+            else {
+                continue;
+            };
+            if !Self::is_expired(&e, token_id) {
+                continue;
+            }
+
+            let owner: Option<Address> = e
+                .storage()
+                .persistent()
+                .get(&DataKey::VoucherOwner(token_id));
+            if let Some(owner) = owner.clone() {
+                let balance = Self::balance_of(&e, owner.clone(), token_id);
+                Self::_burn(&e, owner, token_id, balance);
+            }
+            Self::remove_voucher(&e, token_id, value);
+
+            #[allow(deprecated)]
+            e.events()
+                .publish((symbol_short!("V_Expire"), token_id, owner), value);
+            swept += 1;
+        }
+        swept
+    }
+
+    /// Get a voucher's value, expiry and payout kind. Returns None for
+    /// unknown, redeemed or swept vouchers.
+    pub fn get_voucher(e: Env, token_id: u128) -> Option<VoucherInfo> {
+        let value: u128 = e
             .storage()
             .persistent()
-            .get(&DataKey::TycToken)
-            .expect("Not initialized");
-        let client = soroban_sdk::token::Client::new(&e, &tyc_token);
-        // Transfer from Contract to Redeemer
-        let contract_address = e.current_contract_address();
-        client.transfer(&contract_address, &redeemer, &(tyc_value as i128));
-        // Delete storage
+            .get(&DataKey::VoucherValue(token_id))?;
+        Some(VoucherInfo {
+            value,
+            expires_at: e
+                .storage()
+                .persistent()
+                .get(&DataKey::VoucherExpiry(token_id)),
+            payout: Self::payout_of(&e, token_id),
+            transferable: !e
+                .storage()
+                .persistent()
+                .has(&DataKey::VoucherSoulbound(token_id)),
+        })
+    }
+
+    /// Get what a voucher pays out when redeemed. Returns None for unknown
+    /// vouchers.
+    pub fn get_voucher_payout(e: Env, token_id: u128) -> Option<PayoutKind> {
+        if e.storage()
+            .persistent()
+            .has(&DataKey::VoucherValue(token_id))
+        {
+            Some(Self::payout_of(&e, token_id))
+        } else {
+            None
+        }
+    }
+
+    /// Get the ledger timestamp from which a voucher can no longer be
+    /// redeemed. Returns None for vouchers that never expire.
+    pub fn get_voucher_expiry(e: Env, token_id: u128) -> Option<u64> {
         e.storage()
             .persistent()
-            .remove(&DataKey::VoucherValue(token_id));
-        #[allow(deprecated)]
-        e.events()
-            .publish((symbol_short!("Redeem"), redeemer, token_id), tyc_value);
+            .get(&DataKey::VoucherExpiry(token_id))
     }
 
     /// Withdraw funds from the contract (admin only)
@@ -263,22 +581,18 @@ impl TycoonRewardSystem {
     /// * If token is not in allowlist (TYC or USDC)
     /// * If contract has insufficient balance
     pub fn withdraw_funds(e: Env, token: Address, to: Address, amount: u128) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
+        ownership::require_owner(&e);
+        Self::extend_instance_ttl(&e);
 
         // Validate token is in allowlist (TYC or USDC)
         let tyc_token: Address = e
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::TycToken)
             .expect("Not initialized");
         let usdc_token: Address = e
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::UsdcToken)
             .expect("Not initialized");
 
@@ -304,6 +618,55 @@ impl TycoonRewardSystem {
             .publish((symbol_short!("Withdraw"), token.clone(), to), amount);
     }
 
+    /// Get the current admin
+    pub fn get_admin(e: Env) -> Address {
+        ownership::get_owner(&e)
+    }
+
+    /// Move the admin stored by a release before tycoon_lib::ownership into
+    /// ownership storage (legacy admin only), together with the rest of the
+    /// legacy configuration (see `migrate_config`). Run once after upgrading
+    /// such a deployment.
+    ///
+    /// # Panics
+    /// * If the admin has already been migrated
+    /// * If there is no legacy admin
+    pub fn migrate_admin(e: Env) -> Address {
+        if ownership::is_owner_set(&e) {
+            panic!("Admin already migrated");
+        }
+        let admin = Self::adopt_legacy_admin(&e);
+        admin.require_auth();
+        Self::move_legacy_config(&e);
+        admin
+    }
+
+    /// Propose a new admin (admin only). Takes effect once the proposed
+    /// address calls `accept_ownership`.
+    pub fn transfer_ownership(e: Env, new_admin: Address) {
+        ownership::transfer_ownership(&e, &new_admin);
+    }
+
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_ownership(e: Env) {
+        ownership::accept_ownership(&e);
+    }
+
+    /// Withdraw a pending admin transfer (admin only)
+    pub fn cancel_ownership_transfer(e: Env) {
+        ownership::cancel_ownership_transfer(&e);
+    }
+
+    /// Permanently give up admin rights (admin only)
+    pub fn renounce_ownership(e: Env) {
+        ownership::renounce_ownership(&e);
+    }
+
+    /// Get the admin proposed by `transfer_ownership`, if any
+    pub fn pending_owner(e: Env) -> Option<Address> {
+        ownership::pending_owner(&e)
+    }
+
     // Internal helper to mint tokens
     pub fn get_balance(e: Env, owner: Address, token_id: u128) -> u64 {
         Self::balance_of(&e, owner, token_id)
@@ -317,19 +680,35 @@ impl TycoonRewardSystem {
             .unwrap_or(0)
     }
 
+    /// Get all voucher token IDs held by an address
+    pub fn tokens_of(e: Env, owner: Address) -> Vec<u128> {
+        enumeration::get_owned_tokens(&e, &owner)
+    }
+
+    /// Get the voucher token ID at a specific index for an owner
+    /// Panics if index is out of bounds
+    pub fn token_of_owner_by_index(e: Env, owner: Address, index: u32) -> u128 {
+        enumeration::token_of_owner_by_index(&e, &owner, index)
+            .unwrap_or_else(|| panic!("Index out of bounds"))
+    }
+
     /// Transfer vouchers from one address to another
+    ///
+    /// # Panics
+    /// * If the voucher was minted non-transferable (soulbound)
     pub fn transfer(e: Env, from: Address, to: Address, token_id: u128, amount: u64) {
         from.require_auth();
+        Self::extend_instance_ttl(&e);
 
-        let paused: bool = e
-            .storage()
+        if e.storage()
             .persistent()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            panic!("Contract is paused");
+            .has(&DataKey::VoucherSoulbound(token_id))
+        {
+            panic!("Voucher is not transferable");
         }
 
+        Self::require_not_paused(&e);
+
         Self::_burn(&e, from.clone(), token_id, amount);
         Self::_mint(&e, to.clone(), token_id, amount);
 
@@ -340,6 +719,153 @@ impl TycoonRewardSystem {
 }
 
 impl TycoonRewardSystem {
+    /// Makes the admin stored under the persistent legacy `Admin` key the
+    /// owner and deletes the old entry.
+    fn adopt_legacy_admin(e: &Env) -> Address {
+        let admin: Address = e
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("No legacy admin");
+        ownership::init_owner(e, &admin);
+        e.storage().persistent().remove(&DataKey::Admin);
+        admin
+    }
+
+    /// Moves every configuration key found in persistent storage into
+    /// instance storage and returns how many were moved.
+    fn move_legacy_config(e: &Env) -> u32 {
+        let keys = [
+            DataKey::TycToken,
+            DataKey::UsdcToken,
+            DataKey::VoucherCount,
+            DataKey::Paused,
+            DataKey::BackendMinter,
+            DataKey::CollectiblesContract,
+            DataKey::OutstandingLiability,
+            DataKey::LiabilityOverdraft,
+            DataKey::StreakBonus,
+        ];
+        let mut migrated = 0;
+        for key in keys.iter() {
+            let Some(value) = e.storage().persistent().get::<_, Val>(key) else {
+                continue;
+            };
+            e.storage().instance().set(key, &value);
+            e.storage().persistent().remove(key);
+            migrated += 1;
+        }
+        Self::extend_instance_ttl(e);
+
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("Migrated"),), migrated);
+        migrated
+    }
+
+    fn extend_instance_ttl(e: &Env) {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    fn require_voucher_minter(e: &Env, caller: &Address) {
+        let admin = ownership::get_owner(e);
+        caller.require_auth();
+
+        // Check if caller is admin or backend minter
+        let backend_minter: Option<Address> = e.storage().instance().get(&DataKey::BackendMinter);
+
+        let is_admin = *caller == admin;
+        let is_backend_minter = backend_minter.is_some_and(|minter| minter == *caller);
+
+        if !is_admin && !is_backend_minter {
+            panic!("Unauthorized: only admin or backend minter can mint");
+        }
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Panics while the contract is paused, or when it has no `Paused` flag in
+    /// instance storage: not initialized, or legacy configuration not yet
+    /// migrated.
+    fn require_not_paused(e: &Env) {
+        let paused: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .expect("Not initialized");
+        if paused {
+            panic!("Contract is paused");
+        }
+    }
+
+    fn check_expiry(e: &Env, expires_at: Option<u64>) {
+        if expires_at.is_some_and(|expiry| expiry <= e.ledger().timestamp()) {
+            panic!("Expiry must be in the future");
+        }
+    }
+
+    fn check_payout(e: &Env, payout: &PayoutKind, value: u128) {
+        if let PayoutKind::Collectible(_) = payout {
+            if !e.storage().instance().has(&DataKey::CollectiblesContract) {
+                panic!("Collectibles contract not set");
+            }
+            if value == 0 || value > u64::MAX as u128 {
+                panic!("Invalid collectible amount");
+            }
+        }
+    }
+
+    /// Assigns the next voucher id, stores its value, expiry, payout kind and
+    /// transferability and mints it to `to`.
+    fn create_voucher(
+        e: &Env,
+        to: &Address,
+        value: u128,
+        expires_at: Option<u64>,
+        payout: &PayoutKind,
+        transferable: bool,
+    ) -> u128 {
+        let mut current_id: u128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::VoucherCount)
+            .expect("Not initialized");
+        let token_id = current_id;
+        current_id += 1;
+        e.storage()
+            .instance()
+            .set(&DataKey::VoucherCount, &current_id);
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::VoucherValue(token_id), &value);
+        if let Some(expiry) = expires_at {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherExpiry(token_id), &expiry);
+        }
+        if *payout == PayoutKind::Tyc {
+            let liability = Self::liability(e)
+                .checked_add(value)
+                .expect("Liability overflow");
+            e.storage()
+                .instance()
+                .set(&DataKey::OutstandingLiability, &liability);
+        } else {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherPayout(token_id), payout);
+        }
+        if !transferable {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherSoulbound(token_id), &true);
+        }
+
+        Self::_mint(e, to.clone(), token_id, 1);
+        token_id
+    }
+
     fn _mint(e: &Env, to: Address, token_id: u128, amount: u64) {
         if amount == 0 {
             return;
@@ -354,6 +880,16 @@ impl TycoonRewardSystem {
 
         e.storage().persistent().set(&key, &new_balance);
 
+        // Track who holds a voucher so expired ones can be swept
+        if e.storage()
+            .persistent()
+            .has(&DataKey::VoucherValue(token_id))
+        {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherOwner(token_id), &to);
+        }
+
         // Update OwnedTokenCount if receiving this token for the first time
         if current_balance == 0 {
             let count_key = DataKey::OwnedTokenCount(to.clone());
@@ -361,6 +897,7 @@ impl TycoonRewardSystem {
             e.storage()
                 .persistent()
                 .set(&count_key, &(current_count + 1));
+            enumeration::add_token_to_owner(e, &to, token_id);
         }
 
         #[allow(deprecated)]
@@ -386,6 +923,12 @@ impl TycoonRewardSystem {
             e.storage().persistent().set(&key, &new_balance);
         }
 
+        // Stop tracking the voucher holder once it has none left
+        let owner_key = DataKey::VoucherOwner(token_id);
+        if new_balance == 0 && e.storage().persistent().get(&owner_key) == Some(from.clone()) {
+            e.storage().persistent().remove(&owner_key);
+        }
+
         // Update OwnedTokenCount if losing the last of this token
         if current_balance > 0 && new_balance == 0 {
             let count_key = DataKey::OwnedTokenCount(from.clone());
@@ -398,6 +941,7 @@ impl TycoonRewardSystem {
                     e.storage().persistent().set(&count_key, &updated_count);
                 }
             }
+            enumeration::remove_token_from_owner(e, &from, token_id);
         }
 
         #[allow(deprecated)]
@@ -405,24 +949,90 @@ impl TycoonRewardSystem {
             .publish((symbol_short!("Burn"), from, token_id), amount);
     }
 
-    fn balance_of(e: &Env, owner: Address, token_id: u128) -> u64 {
-        let key = DataKey::Balance(owner, token_id);
-        e.storage().persistent().get(&key).unwrap_or(0)
+    /// Deletes a voucher's value, expiry, payout kind and soulbound flag,
+    /// releasing its TYC liability. If the recorded liability is smaller than
+    /// the voucher's value it drops to zero and `Liab_Gap` reports the
+    /// shortfall, so the admin can reconcile it with `set_outstanding_liability`.
+    fn remove_voucher(e: &Env, token_id: u128, value: u128) {
+        if Self::payout_of(e, token_id) == PayoutKind::Tyc {
+            let recorded = Self::liability(e);
+            if value > recorded {
+                #[allow(deprecated)]
+                e.events()
+                    .publish((symbol_short!("Liab_Gap"), token_id), value - recorded);
+            }
+            e.storage().instance().set(
+                &DataKey::OutstandingLiability,
+                &recorded.saturating_sub(value),
+            );
+        }
+        e.storage()
+            .persistent()
+            .remove(&DataKey::VoucherValue(token_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::VoucherExpiry(token_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::VoucherPayout(token_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::VoucherSoulbound(token_id));
     }
-}
 
-#[contractimpl]
-impl TycoonRewardSystem {
-    // Public wrapper for testing mint
-    pub fn test_mint(e: Env, to: Address, token_id: u128, amount: u64) {
-        Self::_mint(&e, to, token_id, amount);
+    fn check_solvency(e: &Env) {
+        let Some(overdraft) = e
+            .storage()
+            .instance()
+            .get::<_, u128>(&DataKey::LiabilityOverdraft)
+        else {
+            return;
+        };
+        if Self::liability(e) > Self::tyc_balance(e).saturating_add(overdraft) {
+            panic!("Insufficient TYC backing");
+        }
+    }
+
+    fn liability(e: &Env) -> u128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::OutstandingLiability)
+            .unwrap_or(0)
+    }
+
+    fn tyc_balance(e: &Env) -> u128 {
+        let tyc_token: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::TycToken)
+            .expect("Not initialized");
+        let balance =
+            soroban_sdk::token::Client::new(e, &tyc_token).balance(&e.current_contract_address());
+        balance.max(0) as u128
     }
 
-    // Public wrapper for testing burn
-    pub fn test_burn(e: Env, from: Address, token_id: u128, amount: u64) {
-        Self::_burn(&e, from, token_id, amount);
+    fn payout_of(e: &Env, token_id: u128) -> PayoutKind {
+        e.storage()
+            .persistent()
+            .get(&DataKey::VoucherPayout(token_id))
+            .unwrap_or(PayoutKind::Tyc)
+    }
+
+    fn is_expired(e: &Env, token_id: u128) -> bool {
+        e.storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::VoucherExpiry(token_id))
+            .is_some_and(|expiry| e.ledger().timestamp() >= expiry)
+    }
+
+    fn balance_of(e: &Env, owner: Address, token_id: u128) -> u64 {
+        let key = DataKey::Balance(owner, token_id);
+        e.storage().persistent().get(&key).unwrap_or(0)
     }
 }
 
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
 #[cfg(test)]
 mod test;
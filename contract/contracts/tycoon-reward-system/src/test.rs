@@ -1,7 +1,10 @@
 extern crate std;
-use crate::{DataKey, TycoonRewardSystem, TycoonRewardSystemClient};
-use soroban_sdk::testutils::{Address as TestAddress, Events};
-use soroban_sdk::{token, Address, Env};
+use crate::{
+    testutils, Achievement, DataKey, PayoutKind, TycoonRewardSystem, TycoonRewardSystemClient,
+    VoucherInfo, VOUCHER_ID_START,
+};
+use soroban_sdk::testutils::{Address as TestAddress, Events, Ledger};
+use soroban_sdk::{symbol_short, token, vec, Address, BytesN, Env, IntoVal, Symbol};
 
 #[test]
 fn test_simple_event() {
@@ -9,9 +12,8 @@ fn test_simple_event() {
     env.mock_all_auths();
 
     let contract_id = env.register(TycoonRewardSystem, ());
-    let client = TycoonRewardSystemClient::new(&env, &contract_id);
     let user = <soroban_sdk::Address as soroban_sdk::testutils::Address>::generate(&env);
-    client.test_mint(&user, &123, &10); // Uses _mint which emits "Mint"
+    testutils::mint(&env, &contract_id, &user, 123, 10); // Uses _mint which emits "Mint"
 
     let events = env.events().all();
     std::println!("Simple test events: {}", events.len());
@@ -55,7 +57,7 @@ fn test_voucher_flow() {
 
     // 2. Mint Voucher
     let tyc_value = 500u128;
-    let token_id = client.mint_voucher(&admin, &user, &tyc_value);
+    let token_id = client.mint_voucher(&admin, &user, &tyc_value, &None, &PayoutKind::Tyc, &true);
 
     // Verify Voucher Minted
     assert_eq!(client.get_balance(&user, &token_id), 1);
@@ -95,7 +97,6 @@ fn test_pause_and_unpause_admin_only() {
     let env = Env::default();
     env.mock_all_auths();
     let admin = <soroban_sdk::Address as soroban_sdk::testutils::Address>::generate(&env);
-    let user = <soroban_sdk::Address as soroban_sdk::testutils::Address>::generate(&env);
     let tyc_token_admin = <soroban_sdk::Address as soroban_sdk::testutils::Address>::generate(&env);
     let tyc_token_id = env
         .register_stellar_asset_contract_v2(tyc_token_admin.clone())
@@ -111,12 +112,12 @@ fn test_pause_and_unpause_admin_only() {
     // Admin can pause
     client.pause();
     let paused: bool = env.as_contract(&contract_id, || {
-        env.storage().persistent().get(&DataKey::Paused).unwrap()
+        env.storage().instance().get(&DataKey::Paused).unwrap()
     });
     assert!(paused);
     client.unpause();
     let paused: bool = env.as_contract(&contract_id, || {
-        env.storage().persistent().get(&DataKey::Paused).unwrap()
+        env.storage().instance().get(&DataKey::Paused).unwrap()
     });
     assert!(!paused);
     // Non-admin cannot pause
@@ -149,7 +150,7 @@ fn test_redeem_fails_when_paused() {
     client.initialize(&admin, &tyc_token_id, &usdc_token_id);
     token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id, &10000);
     let tyc_value = 500u128;
-    let token_id = client.mint_voucher(&admin, &user, &tyc_value);
+    let token_id = client.mint_voucher(&admin, &user, &tyc_value, &None, &PayoutKind::Tyc, &true);
     // Pause contract
     client.pause();
     // Redeem should fail
@@ -467,7 +468,14 @@ fn test_backend_minter_can_mint() {
 
     // Backend minter can mint
     let tyc_value = 500u128;
-    let token_id = client.mint_voucher(&backend_minter, &user, &tyc_value);
+    let token_id = client.mint_voucher(
+        &backend_minter,
+        &user,
+        &tyc_value,
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
 
     // Verify
     assert_eq!(client.get_balance(&user, &token_id), 1);
@@ -511,7 +519,7 @@ fn test_non_admin_non_minter_cannot_mint() {
 
     // Unauthorized user tries to mint - should panic
     let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        client.mint_voucher(&unauthorized, &user, &500);
+        client.mint_voucher(&unauthorized, &user, &500, &None, &PayoutKind::Tyc, &true);
     }));
     assert!(res.is_err());
 }
@@ -558,7 +566,7 @@ fn test_clear_backend_minter() {
 
     // Now backend minter cannot mint
     let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        client.mint_voucher(&backend_minter, &user, &500);
+        client.mint_voucher(&backend_minter, &user, &500, &None, &PayoutKind::Tyc, &true);
     }));
     assert!(res.is_err());
 }
@@ -597,12 +605,14 @@ fn test_owned_token_count() {
 
     // Mint voucher for user1
     let tyc_value = 500u128;
-    let token_id_1 = client.mint_voucher(&admin, &user1, &tyc_value);
+    let token_id_1 =
+        client.mint_voucher(&admin, &user1, &tyc_value, &None, &PayoutKind::Tyc, &true);
 
     assert_eq!(client.owned_token_count(&user1), 1);
 
     // Mint another voucher for user1
-    let token_id_2 = client.mint_voucher(&admin, &user1, &tyc_value);
+    let token_id_2 =
+        client.mint_voucher(&admin, &user1, &tyc_value, &None, &PayoutKind::Tyc, &true);
     assert_eq!(client.owned_token_count(&user1), 2);
 
     // Balance of tokens
@@ -642,3 +652,1250 @@ fn test_owned_token_count() {
     let user3 = Address::generate(&env);
     assert_eq!(client.owned_token_count(&user3), 0);
 }
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+    let tyc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let usdc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    client.initialize(&admin, &tyc_token_id, &usdc_token_id);
+
+    client.transfer_ownership(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.pending_owner(), Some(new_admin.clone()));
+
+    client.accept_ownership();
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.pending_owner(), None);
+
+    // Old admin has lost its rights, the new one has them
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.set_backend_minter(&admin, &minter);
+    }));
+    assert!(res.is_err());
+    client.set_backend_minter(&new_admin, &minter);
+    assert_eq!(client.get_backend_minter(), Some(minter));
+}
+
+#[test]
+fn test_renounce_ownership_disables_admin_functions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let tyc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let usdc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    client.initialize(&admin, &tyc_token_id, &usdc_token_id);
+
+    client.renounce_ownership();
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.pause();
+    }));
+    assert!(res.is_err());
+
+    // Renouncing does not reopen initialization
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.initialize(&admin, &tyc_token_id, &usdc_token_id);
+    }));
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_migrate_legacy_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let tyc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let usdc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+
+    // Admin stored by a release from before the ownership module
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+    });
+
+    // The old admin still blocks re-initialization
+    let attacker = Address::generate(&env);
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.initialize(&attacker, &tyc_token_id, &usdc_token_id);
+    }));
+    assert!(res.is_err());
+
+    assert_eq!(client.migrate_admin(), admin);
+    assert_eq!(client.get_admin(), admin);
+    client.pause();
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::Admin));
+    });
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.migrate_admin();
+    }));
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_testutils_mint_and_burn() {
+    let env = Env::default();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    testutils::mint(&env, &contract_id, &user, 123, 10);
+    assert_eq!(client.get_balance(&user, &123), 10);
+
+    testutils::burn(&env, &contract_id, &user, 123, 4);
+    assert_eq!(client.get_balance(&user, &123), 6);
+}
+
+#[test]
+fn test_unauthenticated_mint_and_burn_are_not_entrypoints() {
+    // Regression: `test_mint`/`test_burn` used to be contract entrypoints
+    // without any auth. The test build exposes exactly the production
+    // entrypoints, so invoking them by name must fail here too.
+    let env = Env::default();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let user = Address::generate(&env);
+    testutils::mint(&env, &contract_id, &user, 123, 10);
+
+    for name in ["test_mint", "test_burn"] {
+        let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &contract_id,
+            &Symbol::new(&env, name),
+            vec![
+                &env,
+                user.into_val(&env),
+                123u128.into_val(&env),
+                1u64.into_val(&env),
+            ],
+        );
+        assert!(result.is_err(), "{name} must not be callable");
+    }
+
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    assert_eq!(client.get_balance(&user, &123), 10);
+}
+
+// Voucher expiry
+
+/// Registers and initializes the reward system with 10,000 TYC in it.
+/// Returns (client, contract_id, admin, tyc_token_id).
+fn setup_funded(env: &Env) -> (TycoonRewardSystemClient<'_>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let tyc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let usdc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(env, &contract_id);
+    client.initialize(&admin, &tyc_token_id, &usdc_token_id);
+    token::StellarAssetClient::new(env, &tyc_token_id).mint(&contract_id, &10000);
+
+    (client, contract_id, admin, tyc_token_id)
+}
+
+#[test]
+fn test_voucher_redeemable_before_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, tyc_token_id) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let token_id = client.mint_voucher(&admin, &user, &500, &Some(2_000), &PayoutKind::Tyc, &true);
+    assert_eq!(client.get_voucher_expiry(&token_id), Some(2_000));
+
+    env.ledger().set_timestamp(1_999);
+    client.redeem_voucher_from(&user, &token_id);
+
+    assert_eq!(token::Client::new(&env, &tyc_token_id).balance(&user), 500);
+    assert_eq!(client.get_voucher_expiry(&token_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Voucher expired")]
+fn test_expired_voucher_cannot_be_redeemed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let token_id = client.mint_voucher(&admin, &user, &500, &Some(2_000), &PayoutKind::Tyc, &true);
+
+    env.ledger().set_timestamp(2_000);
+    client.redeem_voucher_from(&user, &token_id);
+}
+
+#[test]
+#[should_panic(expected = "Expiry must be in the future")]
+fn test_mint_voucher_rejects_past_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+
+    client.mint_voucher(
+        &admin,
+        &Address::generate(&env),
+        &500,
+        &Some(1_000),
+        &PayoutKind::Tyc,
+        &true,
+    );
+}
+
+#[test]
+fn test_sweep_expired_deletes_only_expired_vouchers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, contract_id, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let expired = client.mint_voucher(&admin, &user, &500, &Some(2_000), &PayoutKind::Tyc, &true);
+    let live = client.mint_voucher(&admin, &user, &300, &Some(5_000), &PayoutKind::Tyc, &true);
+    let forever = client.mint_voucher(&admin, &user, &100, &None, &PayoutKind::Tyc, &true);
+
+    env.ledger().set_timestamp(3_000);
+    let swept = client.sweep_expired(&vec![&env, expired, live, forever, 42]);
+    assert_eq!(swept, 1);
+
+    let events = env.events().all();
+    let (emitter, topics, data) = events.last().unwrap();
+    assert_eq!(emitter, contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("V_Expire"), expired, Some(user.clone())).into_val(&env)
+    );
+    let value: u128 = data.into_val(&env);
+    assert_eq!(value, 500);
+
+    assert_eq!(client.get_balance(&user, &expired), 0);
+    assert_eq!(client.get_voucher_expiry(&expired), None);
+    assert_eq!(client.get_balance(&user, &live), 1);
+    assert_eq!(client.get_balance(&user, &forever), 1);
+    assert_eq!(client.owned_token_count(&user), 2);
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.redeem_voucher_from(&user, &expired);
+    }));
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_sweep_expired_burns_from_current_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let token_id = client.mint_voucher(&admin, &user, &500, &Some(2_000), &PayoutKind::Tyc, &true);
+    client.transfer(&user, &other, &token_id, &1);
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.sweep_expired(&vec![&env, token_id]), 1);
+
+    assert_eq!(client.get_balance(&other, &token_id), 0);
+    assert_eq!(client.owned_token_count(&other), 0);
+}
+
+#[test]
+fn test_sweep_expired_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+    let token_id = client.mint_voucher(&admin, &user, &500, &Some(2_000), &PayoutKind::Tyc, &true);
+    env.ledger().set_timestamp(2_000);
+
+    env.set_auths(&[]);
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.sweep_expired(&vec![&env, token_id]);
+    }));
+    assert!(res.is_err());
+    assert_eq!(client.get_balance(&user, &token_id), 1);
+}
+
+// Batch voucher minting
+
+#[test]
+fn test_mint_voucher_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id, admin, tyc_token_id) = setup_funded(&env);
+    let users = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let recipients = vec![&env, users[0].clone(), users[1].clone(), users[2].clone()];
+
+    let token_ids = client.mint_voucher_batch(
+        &admin,
+        &recipients,
+        &vec![&env, 100u128, 200, 300],
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+
+    let events = env.events().all();
+    let batch_events: std::vec::Vec<_> = events
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("V_Batch").to_val())
+        })
+        .collect();
+    assert_eq!(batch_events.len(), 1);
+    let (emitter, topics, data) = batch_events[0].clone();
+    assert_eq!(emitter, contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("V_Batch"), admin.clone()).into_val(&env)
+    );
+    let (first_id, count, total): (u128, u32, u128) = data.into_val(&env);
+    assert_eq!(
+        (first_id, count, total),
+        (token_ids.get(0).unwrap(), 3, 600)
+    );
+    assert!(!events.iter().any(|(_, topics, _)| topics
+        .get(0)
+        .unwrap()
+        .shallow_eq(&symbol_short!("V_Mint").to_val())));
+
+    assert_eq!(token_ids.len(), 3);
+    for (i, user) in users.iter().enumerate() {
+        let token_id = token_ids.get(i as u32).unwrap();
+        assert_eq!(token_id, first_id + i as u128);
+        assert_eq!(client.get_balance(user, &token_id), 1);
+    }
+
+    client.redeem_voucher_from(&users[2], &token_ids.get(2).unwrap());
+    assert_eq!(
+        token::Client::new(&env, &tyc_token_id).balance(&users[2]),
+        300
+    );
+}
+
+#[test]
+fn test_mint_voucher_batch_by_backend_minter_with_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let backend_minter = Address::generate(&env);
+    client.set_backend_minter(&admin, &backend_minter);
+    let user = Address::generate(&env);
+
+    let token_ids = client.mint_voucher_batch(
+        &backend_minter,
+        &vec![&env, user.clone()],
+        &vec![&env, 100u128],
+        &Some(2_000),
+        &PayoutKind::Tyc,
+        &true,
+    );
+
+    assert_eq!(env.auths()[0].0, backend_minter);
+    assert_eq!(
+        client.get_voucher_expiry(&token_ids.get(0).unwrap()),
+        Some(2_000)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only admin or backend minter can mint")]
+fn test_mint_voucher_batch_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _) = setup_funded(&env);
+    let stranger = Address::generate(&env);
+
+    client.mint_voucher_batch(
+        &stranger,
+        &vec![&env, stranger.clone()],
+        &vec![&env, 100u128],
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipients and values length mismatch")]
+fn test_mint_voucher_batch_length_mismatch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+
+    client.mint_voucher_batch(
+        &admin,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 100u128],
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Batch too large")]
+fn test_mint_voucher_batch_size_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+
+    let mut recipients = soroban_sdk::Vec::new(&env);
+    let mut values = soroban_sdk::Vec::new(&env);
+    for _ in 0..=crate::MAX_VOUCHER_BATCH {
+        recipients.push_back(Address::generate(&env));
+        values.push_back(100u128);
+    }
+    client.mint_voucher_batch(&admin, &recipients, &values, &None, &PayoutKind::Tyc, &true);
+}
+
+#[test]
+#[should_panic(expected = "Empty batch")]
+fn test_mint_voucher_batch_rejects_empty_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+
+    client.mint_voucher_batch(
+        &admin,
+        &soroban_sdk::Vec::new(&env),
+        &soroban_sdk::Vec::new(&env),
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+}
+
+fn usdc_token(env: &Env, contract_id: &Address) -> Address {
+    env.as_contract(contract_id, || {
+        env.storage().instance().get(&DataKey::UsdcToken).unwrap()
+    })
+}
+
+#[test]
+fn test_usdc_voucher_pays_usdc() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id, admin, tyc_token_id) = setup_funded(&env);
+    let usdc_token_id = usdc_token(&env, &contract_id);
+    token::StellarAssetClient::new(&env, &usdc_token_id).mint(&contract_id, &1000);
+    let user = Address::generate(&env);
+
+    let token_id = client.mint_voucher(&admin, &user, &250, &None, &PayoutKind::Usdc, &true);
+    assert_eq!(client.get_voucher_payout(&token_id), Some(PayoutKind::Usdc));
+
+    client.redeem_voucher_from(&user, &token_id);
+
+    assert_eq!(token::Client::new(&env, &usdc_token_id).balance(&user), 250);
+    assert_eq!(token::Client::new(&env, &tyc_token_id).balance(&user), 0);
+    assert_eq!(client.get_voucher_payout(&token_id), None);
+}
+
+#[test]
+fn test_collectible_voucher_mints_collectible() {
+    use tycoon_collectibles::{TycoonCollectibles, TycoonCollectiblesClient};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id, admin, tyc_token_id) = setup_funded(&env);
+
+    let collectibles_id = env.register(TycoonCollectibles, ());
+    let collectibles = TycoonCollectiblesClient::new(&env, &collectibles_id);
+    collectibles.initialize(&admin);
+    collectibles.set_backend_minter(&contract_id);
+    client.set_collectibles_contract(&collectibles_id);
+    assert_eq!(client.get_collectibles_contract(), Some(collectibles_id));
+
+    let user = Address::generate(&env);
+    let token_id = client.mint_voucher(
+        &admin,
+        &user,
+        &3,
+        &None,
+        &PayoutKind::Collectible(42),
+        &true,
+    );
+    assert_eq!(
+        client.get_voucher_payout(&token_id),
+        Some(PayoutKind::Collectible(42))
+    );
+
+    client.redeem_voucher_from(&user, &token_id);
+
+    assert_eq!(collectibles.balance_of(&user, &42), 3);
+    assert_eq!(token::Client::new(&env, &tyc_token_id).balance(&user), 0);
+    assert_eq!(client.get_balance(&user, &token_id), 0);
+    assert_eq!(client.get_voucher_payout(&token_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Collectibles contract not set")]
+fn test_collectible_voucher_requires_collectibles_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+
+    client.mint_voucher(
+        &admin,
+        &Address::generate(&env),
+        &1,
+        &None,
+        &PayoutKind::Collectible(42),
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid collectible amount")]
+fn test_collectible_voucher_rejects_zero_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    client.set_collectibles_contract(&Address::generate(&env));
+
+    client.mint_voucher(
+        &admin,
+        &Address::generate(&env),
+        &0,
+        &None,
+        &PayoutKind::Collectible(42),
+        &true,
+    );
+}
+
+#[test]
+fn test_outstanding_liability_tracks_tyc_vouchers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let redeemed = client.mint_voucher(&admin, &user, &500, &None, &PayoutKind::Tyc, &true);
+    let expiring = client.mint_voucher(&admin, &user, &300, &Some(2_000), &PayoutKind::Tyc, &true);
+    client.mint_voucher(&admin, &user, &200, &None, &PayoutKind::Usdc, &true);
+    assert_eq!(client.outstanding_liability(), 800);
+    assert!(client.is_solvent());
+
+    client.redeem_voucher_from(&user, &redeemed);
+    assert_eq!(client.outstanding_liability(), 300);
+
+    env.ledger().set_timestamp(2_000);
+    client.sweep_expired(&vec![&env, expiring]);
+    assert_eq!(client.outstanding_liability(), 0);
+}
+
+#[test]
+fn test_is_solvent_compares_liability_with_tyc_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, tyc_token_id) = setup_funded(&env);
+
+    client.mint_voucher(
+        &admin,
+        &Address::generate(&env),
+        &500,
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+    client.withdraw_funds(&tyc_token_id, &admin, &9_500);
+    assert!(client.is_solvent());
+
+    client.withdraw_funds(&tyc_token_id, &admin, &1);
+    assert!(!client.is_solvent());
+}
+
+#[test]
+fn test_mint_unchecked_without_overdraft() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    assert_eq!(client.get_liability_overdraft(), None);
+
+    client.mint_voucher(
+        &admin,
+        &Address::generate(&env),
+        &20_000,
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+    assert_eq!(client.outstanding_liability(), 20_000);
+    assert!(!client.is_solvent());
+}
+
+#[test]
+fn test_mint_within_overdraft() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    client.set_liability_overdraft(&Some(100));
+    assert_eq!(client.get_liability_overdraft(), Some(100));
+
+    let user = Address::generate(&env);
+    client.mint_voucher(&admin, &user, &10_100, &None, &PayoutKind::Tyc, &true);
+
+    // Only TYC vouchers count towards the liability
+    client.mint_voucher(&admin, &user, &500, &None, &PayoutKind::Usdc, &true);
+    assert!(client
+        .try_mint_voucher(&admin, &user, &1, &None, &PayoutKind::Tyc, &true)
+        .is_err());
+
+    client.set_liability_overdraft(&None);
+    client.mint_voucher(&admin, &user, &1, &None, &PayoutKind::Tyc, &true);
+    assert_eq!(client.outstanding_liability(), 10_101);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient TYC backing")]
+fn test_mint_rejected_beyond_overdraft() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    client.set_liability_overdraft(&Some(0));
+
+    client.mint_voucher(
+        &admin,
+        &Address::generate(&env),
+        &10_001,
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Insufficient TYC backing")]
+fn test_mint_voucher_batch_rejected_beyond_overdraft() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    client.set_liability_overdraft(&Some(0));
+
+    client.mint_voucher_batch(
+        &admin,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 6_000u128, 5_000],
+        &None,
+        &PayoutKind::Tyc,
+        &true,
+    );
+}
+
+#[test]
+fn test_set_liability_overdraft_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _) = setup_funded(&env);
+    env.set_auths(&[]);
+
+    assert!(client.try_set_liability_overdraft(&Some(0)).is_err());
+}
+
+/// Mints a TYC voucher the way releases without liability tracking did,
+/// leaving the outstanding liability untouched.
+fn mint_untracked_voucher(
+    env: &Env,
+    client: &TycoonRewardSystemClient,
+    contract_id: &Address,
+    admin: &Address,
+    user: &Address,
+    value: u128,
+) -> u128 {
+    let liability = client.outstanding_liability();
+    let token_id = client.mint_voucher(admin, user, &value, &None, &PayoutKind::Tyc, &true);
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::OutstandingLiability, &liability);
+    });
+    token_id
+}
+
+#[test]
+fn test_set_outstanding_liability_backfills_legacy_vouchers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let legacy = mint_untracked_voucher(&env, &client, &contract_id, &admin, &user, 400);
+    assert_eq!(client.outstanding_liability(), 0);
+
+    client.set_outstanding_liability(&400);
+    client.mint_voucher(&admin, &user, &100, &None, &PayoutKind::Tyc, &true);
+    assert_eq!(client.outstanding_liability(), 500);
+
+    client.redeem_voucher_from(&user, &legacy);
+    assert_eq!(client.outstanding_liability(), 100);
+}
+
+#[test]
+fn test_redeem_beyond_recorded_liability_reports_gap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id, admin, tyc_token) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    client.mint_voucher(&admin, &user, &100, &None, &PayoutKind::Tyc, &true);
+    let legacy = mint_untracked_voucher(&env, &client, &contract_id, &admin, &user, 400);
+    assert_eq!(client.outstanding_liability(), 100);
+
+    client.redeem_voucher_from(&user, &legacy);
+
+    let events = env.events().all();
+    let (emitter, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| *topics == (symbol_short!("Liab_Gap"), legacy).into_val(&env))
+        .expect("Liab_Gap not emitted");
+    assert_eq!(emitter, contract_id);
+    let shortfall: u128 = data.into_val(&env);
+    assert_eq!(shortfall, 300);
+    assert_eq!(client.outstanding_liability(), 0);
+    assert_eq!(token::Client::new(&env, &tyc_token).balance(&user), 400);
+}
+
+#[test]
+fn test_set_outstanding_liability_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _) = setup_funded(&env);
+    env.set_auths(&[]);
+
+    assert!(client.try_set_outstanding_liability(&0).is_err());
+}
+
+#[test]
+fn test_voucher_enumeration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    assert!(client.tokens_of(&user).is_empty());
+
+    let first = client.mint_voucher(&admin, &user, &100, &None, &PayoutKind::Tyc, &true);
+    let second = client.mint_voucher(&admin, &user, &200, &None, &PayoutKind::Tyc, &true);
+    let third = client.mint_voucher(&admin, &user, &300, &None, &PayoutKind::Tyc, &true);
+    assert_eq!(client.tokens_of(&user), vec![&env, first, second, third]);
+    assert_eq!(client.token_of_owner_by_index(&user, &1), second);
+
+    // Removing a voucher moves the last one into its slot
+    client.transfer(&user, &other, &first, &1);
+    assert_eq!(client.tokens_of(&user), vec![&env, third, second]);
+    assert_eq!(client.tokens_of(&other), vec![&env, first]);
+    assert_eq!(client.token_of_owner_by_index(&user, &0), third);
+
+    client.redeem_voucher_from(&user, &second);
+    assert_eq!(client.tokens_of(&user), vec![&env, third]);
+
+    client.redeem_voucher_from(&user, &third);
+    assert!(client.tokens_of(&user).is_empty());
+    assert_eq!(client.owned_token_count(&user), 0);
+}
+
+#[test]
+fn test_swept_voucher_leaves_enumeration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let expiring = client.mint_voucher(&admin, &user, &100, &Some(2_000), &PayoutKind::Tyc, &true);
+    let kept = client.mint_voucher(&admin, &user, &100, &None, &PayoutKind::Tyc, &true);
+
+    env.ledger().set_timestamp(2_000);
+    client.sweep_expired(&vec![&env, expiring]);
+    assert_eq!(client.tokens_of(&user), vec![&env, kept]);
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn test_token_of_owner_by_index_out_of_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    client.mint_voucher(&admin, &user, &100, &None, &PayoutKind::Tyc, &true);
+    client.token_of_owner_by_index(&user, &1);
+}
+
+#[test]
+fn test_get_voucher() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let token_id = client.mint_voucher(&admin, &user, &500, &Some(2_000), &PayoutKind::Usdc, &true);
+    assert_eq!(
+        client.get_voucher(&token_id),
+        Some(VoucherInfo {
+            value: 500,
+            expires_at: Some(2_000),
+            payout: PayoutKind::Usdc,
+            transferable: true,
+        })
+    );
+
+    let token_id = client.mint_voucher(&admin, &user, &300, &None, &PayoutKind::Tyc, &false);
+    assert_eq!(
+        client.get_voucher(&token_id),
+        Some(VoucherInfo {
+            value: 300,
+            expires_at: None,
+            payout: PayoutKind::Tyc,
+            transferable: false,
+        })
+    );
+
+    client.redeem_voucher_from(&user, &token_id);
+    assert_eq!(client.get_voucher(&token_id), None);
+}
+
+#[test]
+fn test_soulbound_voucher_redeemable_by_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, tyc_token_id) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let token_id = client.mint_voucher(&admin, &user, &500, &None, &PayoutKind::Tyc, &false);
+    assert!(client
+        .try_transfer(&user, &Address::generate(&env), &token_id, &1)
+        .is_err());
+    assert_eq!(client.get_balance(&user, &token_id), 1);
+
+    client.redeem_voucher_from(&user, &token_id);
+    assert_eq!(token::Client::new(&env, &tyc_token_id).balance(&user), 500);
+}
+
+#[test]
+#[should_panic(expected = "Voucher is not transferable")]
+fn test_soulbound_voucher_cannot_be_transferred() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    let user = Address::generate(&env);
+
+    let token_ids = client.mint_voucher_batch(
+        &admin,
+        &vec![&env, user.clone()],
+        &vec![&env, 100u128],
+        &None,
+        &PayoutKind::Tyc,
+        &false,
+    );
+    client.transfer(
+        &user,
+        &Address::generate(&env),
+        &token_ids.get(0).unwrap(),
+        &1,
+    );
+}
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_claim_one_time_achievement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    let backend_minter = Address::generate(&env);
+    client.set_backend_minter(&admin, &backend_minter);
+    let player = Address::generate(&env);
+
+    client.set_achievement(&1, &500, &false);
+    assert_eq!(
+        client.get_achievement(&1),
+        Some(Achievement {
+            reward_value: 500,
+            repeatable: false,
+        })
+    );
+
+    let key = BytesN::from_array(&env, &[1; 32]);
+    let token_id = client.claim_achievement(&backend_minter, &player, &1, &key);
+    assert_eq!(env.auths()[0].0, backend_minter);
+    assert_eq!(client.get_voucher(&token_id).unwrap().value, 500);
+    assert!(client.has_claimed_achievement(&player, &1));
+
+    // Retrying the same claim returns the same voucher without minting
+    assert_eq!(
+        client.claim_achievement(&backend_minter, &player, &1, &key),
+        token_id
+    );
+    assert_eq!(client.tokens_of(&player), vec![&env, token_id]);
+
+    // A new claim of a one-time achievement is rejected
+    let other_key = BytesN::from_array(&env, &[2; 32]);
+    assert!(client
+        .try_claim_achievement(&backend_minter, &player, &1, &other_key)
+        .is_err());
+}
+
+#[test]
+fn test_claim_repeatable_achievement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    let player = Address::generate(&env);
+    client.set_achievement(&7, &100, &true);
+
+    let first = client.claim_achievement(&admin, &player, &7, &BytesN::from_array(&env, &[1; 32]));
+    let second = client.claim_achievement(&admin, &player, &7, &BytesN::from_array(&env, &[2; 32]));
+
+    assert_ne!(first, second);
+    assert_eq!(client.tokens_of(&player), vec![&env, first, second]);
+    assert!(!client.has_claimed_achievement(&player, &7));
+    assert_eq!(client.outstanding_liability(), 200);
+}
+
+#[test]
+#[should_panic(expected = "Idempotency key already used")]
+fn test_claim_achievement_rejects_reused_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    client.set_achievement(&1, &100, &true);
+    let key = BytesN::from_array(&env, &[1; 32]);
+
+    client.claim_achievement(&admin, &Address::generate(&env), &1, &key);
+    client.claim_achievement(&admin, &Address::generate(&env), &1, &key);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only admin or backend minter can mint")]
+fn test_claim_achievement_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _) = setup_funded(&env);
+    client.set_achievement(&1, &100, &true);
+    let stranger = Address::generate(&env);
+
+    client.claim_achievement(
+        &stranger,
+        &stranger,
+        &1,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Achievement not found")]
+fn test_claim_unknown_achievement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, admin, _) = setup_funded(&env);
+    client.set_achievement(&1, &100, &true);
+    client.remove_achievement(&1);
+
+    client.claim_achievement(
+        &admin,
+        &Address::generate(&env),
+        &1,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+}
+
+#[test]
+fn test_achievement_admin_functions_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _) = setup_funded(&env);
+    env.set_auths(&[]);
+
+    assert!(client.try_set_achievement(&1, &100, &true).is_err());
+    assert!(client.try_remove_achievement(&1).is_err());
+    assert!(client.try_set_streak_bonus(&100, &1_000).is_err());
+}
+
+#[test]
+fn test_login_streak() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10 * DAY);
+    let (client, _, admin, _) = setup_funded(&env);
+    let player = Address::generate(&env);
+    assert_eq!(client.get_streak(&player), 0);
+
+    assert_eq!(client.record_login(&admin, &player), 1);
+    env.ledger().set_timestamp(10 * DAY + 3_600);
+    assert_eq!(client.record_login(&admin, &player), 1);
+    env.ledger().set_timestamp(11 * DAY);
+    assert_eq!(client.record_login(&admin, &player), 2);
+    env.ledger().set_timestamp(12 * DAY + 5);
+    assert_eq!(client.record_login(&admin, &player), 3);
+
+    // Missing a day breaks the streak
+    env.ledger().set_timestamp(13 * DAY);
+    assert_eq!(client.get_streak(&player), 3);
+    env.ledger().set_timestamp(14 * DAY);
+    assert_eq!(client.get_streak(&player), 0);
+    assert_eq!(client.record_login(&admin, &player), 1);
+}
+
+#[test]
+fn test_streak_scales_achievement_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10 * DAY);
+    let (client, _, admin, _) = setup_funded(&env);
+    let player = Address::generate(&env);
+    client.set_achievement(&1, &500, &true);
+    client.set_streak_bonus(&1_000, &2_500);
+    assert_eq!(client.get_streak_bonus(), (1_000, 2_500));
+
+    for day in 10..13 {
+        env.ledger().set_timestamp(day * DAY);
+        client.record_login(&admin, &player);
+    }
+    let token_id =
+        client.claim_achievement(&admin, &player, &1, &BytesN::from_array(&env, &[1; 32]));
+    // Three-day streak: two bonus days at 10%
+    assert_eq!(client.get_voucher(&token_id).unwrap().value, 600);
+
+    for day in 13..15 {
+        env.ledger().set_timestamp(day * DAY);
+        client.record_login(&admin, &player);
+    }
+    let token_id =
+        client.claim_achievement(&admin, &player, &1, &BytesN::from_array(&env, &[2; 32]));
+    // Five-day streak: the bonus is capped at 25%
+    assert_eq!(client.get_voucher(&token_id).unwrap().value, 625);
+}
+
+#[test]
+fn test_initialize_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+
+    let result = client.try_initialize(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_config_kept_in_instance_storage() {
+    use soroban_sdk::testutils::storage::Instance as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, contract_id, admin, _) = setup_funded(&env);
+    client.set_backend_minter(&admin, &Address::generate(&env));
+
+    env.as_contract(&contract_id, || {
+        for key in [
+            DataKey::TycToken,
+            DataKey::UsdcToken,
+            DataKey::VoucherCount,
+            DataKey::Paused,
+            DataKey::BackendMinter,
+        ] {
+            assert!(env.storage().instance().has(&key));
+            assert!(!env.storage().persistent().has(&key));
+        }
+        assert!(env.storage().instance().get_ttl() >= 29 * 17_280);
+    });
+}
+
+/// Writes the persistent-only state of a deployment that predates instance
+/// storage and tycoon_lib::ownership. Returns (admin, tyc token, backend minter).
+fn seed_legacy_deployment(env: &Env, contract_id: &Address) -> (Address, Address, Address) {
+    let admin = Address::generate(env);
+    let backend_minter = Address::generate(env);
+    let tyc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let usdc_token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    env.as_contract(contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Admin, &admin);
+        storage.set(&DataKey::TycToken, &tyc_token_id);
+        storage.set(&DataKey::UsdcToken, &usdc_token_id);
+        storage.set(&DataKey::VoucherCount, &(VOUCHER_ID_START + 7));
+        storage.set(&DataKey::Paused, &true);
+        storage.set(&DataKey::BackendMinter, &backend_minter);
+    });
+    (admin, tyc_token_id, backend_minter)
+}
+
+#[test]
+fn test_migrate_config_from_persistent_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    let (admin, tyc_token_id, backend_minter) = seed_legacy_deployment(&env, &contract_id);
+
+    assert_eq!(client.migrate_config(), 5);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.migrate_config(), 0);
+
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::Admin));
+        assert!(!env.storage().persistent().has(&DataKey::TycToken));
+        assert!(!env.storage().persistent().has(&DataKey::BackendMinter));
+    });
+    assert_eq!(client.get_backend_minter(), Some(backend_minter.clone()));
+
+    // The migrated instance cannot be claimed through initialize
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.initialize(&Address::generate(&env), &tyc_token_id, &tyc_token_id);
+    }));
+    assert!(res.is_err());
+
+    // Config carried over: still paused, ids continue from the old counter
+    let user = Address::generate(&env);
+    let token_id =
+        client.mint_voucher(&backend_minter, &user, &500, &None, &PayoutKind::Tyc, &true);
+    assert_eq!(token_id, VOUCHER_ID_START + 7);
+    token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id, &500);
+    assert!(client.try_redeem_voucher_from(&user, &token_id).is_err());
+
+    client.unpause();
+    client.redeem_voucher_from(&user, &token_id);
+    assert_eq!(token::Client::new(&env, &tyc_token_id).balance(&user), 500);
+}
+
+#[test]
+fn test_migrate_admin_then_mint_voucher() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    let (admin, tyc_token_id, backend_minter) = seed_legacy_deployment(&env, &contract_id);
+
+    assert_eq!(client.migrate_admin(), admin);
+    assert_eq!(env.auths()[0].0, admin);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::VoucherCount));
+        assert!(!env.storage().persistent().has(&DataKey::Paused));
+    });
+    assert_eq!(client.migrate_config(), 0);
+
+    // Ids continue from the legacy counter instead of reusing old ones
+    let user = Address::generate(&env);
+    let token_id =
+        client.mint_voucher(&backend_minter, &user, &500, &None, &PayoutKind::Tyc, &true);
+    assert_eq!(token_id, VOUCHER_ID_START + 7);
+
+    // The legacy pause carried over
+    assert!(client
+        .try_transfer(&user, &Address::generate(&env), &token_id, &1)
+        .is_err());
+    token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id, &500);
+    assert!(client.try_redeem_voucher_from(&user, &token_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Not initialized")]
+fn test_unmigrated_legacy_deployment_rejects_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    seed_legacy_deployment(&env, &contract_id);
+    let user = Address::generate(&env);
+    testutils::mint(&env, &contract_id, &user, VOUCHER_ID_START, 1);
+
+    client.transfer(&user, &Address::generate(&env), &VOUCHER_ID_START, &1);
+}
+
+#[test]
+fn test_initialize_rejects_legacy_deployment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    let tyc_token_id = Address::generate(&env);
+
+    // Config left in persistent storage is enough to block a takeover
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TycToken, &tyc_token_id);
+    });
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.initialize(&Address::generate(&env), &tyc_token_id, &tyc_token_id);
+    }));
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_migrate_config_requires_legacy_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(TycoonRewardSystem, ());
+    let client = TycoonRewardSystemClient::new(&env, &contract_id);
+    seed_legacy_deployment(&env, &contract_id);
+
+    assert!(client.try_migrate_config().is_err());
+    env.as_contract(&contract_id, || {
+        assert!(env.storage().persistent().has(&DataKey::Admin));
+    });
+}
+
+#[test]
+fn test_migrate_config_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _) = setup_funded(&env);
+    env.set_auths(&[]);
+
+    assert!(client.try_migrate_config().is_err());
+}
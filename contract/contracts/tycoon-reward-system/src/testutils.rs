@@ -0,0 +1,25 @@
+//! Test-only helpers for seeding and removing reward system balances.
+//!
+//! These run `_mint`/`_burn` directly inside the contract's context instead
+//! of going through an entrypoint, so they never become part of the deployed
+//! contract's interface. Available in this crate's tests and, for other
+//! crates, behind the `testutils` feature.
+
+use crate::TycoonRewardSystem;
+use soroban_sdk::{Address, Env};
+
+/// Mints `amount` of `token_id` to `to` on the reward system at
+/// `contract_id`, without any authorization.
+pub fn mint(env: &Env, contract_id: &Address, to: &Address, token_id: u128, amount: u64) {
+    env.as_contract(contract_id, || {
+        TycoonRewardSystem::_mint(env, to.clone(), token_id, amount)
+    });
+}
+
+/// Burns `amount` of `token_id` from `from` on the reward system at
+/// `contract_id`, without any authorization.
+pub fn burn(env: &Env, contract_id: &Address, from: &Address, token_id: u128, amount: u64) {
+    env.as_contract(contract_id, || {
+        TycoonRewardSystem::_burn(env, from.clone(), token_id, amount)
+    });
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when the admin adds or changes a staking term.
+#[contractevent(topics = ["TermSet"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TermSet {
+    #[topic]
+    pub term: u64,
+    pub reward_rate_bps: u32,
+}
+
+/// Emitted when the admin stops offering a staking term.
+#[contractevent(topics = ["TermRemoved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TermRemoved {
+    #[topic]
+    pub term: u64,
+}
+
+/// Emitted when TYC is added to the reward pool.
+#[contractevent(topics = ["RewardsFunded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsFunded {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin takes unreserved TYC out of the reward pool.
+#[contractevent(topics = ["RewardsWithdrawn"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsWithdrawn {
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a player opens a stake.
+#[contractevent(topics = ["Staked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Staked {
+    #[topic]
+    pub player: Address,
+    pub stake_id: u64,
+    pub amount: i128,
+    pub term: u64,
+    pub reward: i128,
+}
+
+/// Emitted when a player closes a stake. A stake closed before its term
+/// ended has `reward` zero and pays `penalty` out of its principal.
+#[contractevent(topics = ["Unstaked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unstaked {
+    #[topic]
+    pub player: Address,
+    pub stake_id: u64,
+    pub amount: i128,
+    pub reward: i128,
+    pub penalty: i128,
+}
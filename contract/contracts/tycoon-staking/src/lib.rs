@@ -0,0 +1,362 @@
+#![no_std]
+
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+pub use storage::StakePosition;
+use tycoon_lib::ownership;
+
+/// Denominator for reward rates and penalties expressed in basis points.
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Reward rates are annual; terms and elapsed time are in seconds.
+const SECONDS_PER_YEAR: i128 = 365 * 24 * 60 * 60;
+
+#[contract]
+pub struct TycoonStaking;
+
+#[contractimpl]
+impl TycoonStaking {
+    /// Initialize the contract with its admin and the TYC token it stakes.
+    ///
+    /// Must be called exactly once. `admin` must sign the transaction.
+    ///
+    /// # Panics
+    /// - `"Contract already initialized"` if called more than once.
+    pub fn initialize(env: Env, admin: Address, token: Address) {
+        if ownership::is_owner_set(&env) {
+            panic!("Contract already initialized");
+        }
+
+        admin.require_auth();
+
+        ownership::init_owner(&env, &admin);
+        storage::set_token(&env, &token);
+    }
+
+    // -----------------------------------------------------------------------
+    // Admin configuration
+    // -----------------------------------------------------------------------
+
+    /// Offer stakes locked for `term` seconds earning `reward_rate_bps` per
+    /// year (admin only). Changing the rate of an existing term only affects
+    /// new stakes.
+    ///
+    /// Emits `TermSet`.
+    ///
+    /// # Panics
+    /// - `"Term must be positive"` — `term` is zero.
+    pub fn set_term(env: Env, term: u64, reward_rate_bps: u32) {
+        ownership::require_owner(&env);
+
+        if term == 0 {
+            panic!("Term must be positive");
+        }
+        storage::set_term_rate(&env, term, reward_rate_bps);
+
+        events::TermSet {
+            term,
+            reward_rate_bps,
+        }
+        .publish(&env);
+    }
+
+    /// Stop offering `term` for new stakes (admin only). Open stakes keep
+    /// their term and rate.
+    ///
+    /// Emits `TermRemoved`.
+    ///
+    /// # Panics
+    /// - `"Unknown staking term"` — `term` is not offered.
+    pub fn remove_term(env: Env, term: u64) {
+        ownership::require_owner(&env);
+
+        if storage::get_term_rate(&env, term).is_none() {
+            panic!("Unknown staking term");
+        }
+        storage::remove_term(&env, term);
+
+        events::TermRemoved { term }.publish(&env);
+    }
+
+    /// Set the share of the principal, in basis points, that is kept when a
+    /// stake is closed before its term ends (admin only). Only stakes opened
+    /// afterwards are affected; open stakes keep the penalty in force when they
+    /// were opened.
+    ///
+    /// # Panics
+    /// - `"Penalty cannot exceed 100%"` — `penalty_bps` is above 10 000.
+    pub fn set_early_unstake_penalty(env: Env, penalty_bps: u32) {
+        ownership::require_owner(&env);
+
+        if penalty_bps as i128 > BPS_DENOMINATOR {
+            panic!("Penalty cannot exceed 100%");
+        }
+        storage::set_early_unstake_penalty_bps(&env, penalty_bps);
+    }
+
+    /// Add `amount` TYC from `from` to the reward pool. Anyone may fund it.
+    ///
+    /// Emits `RewardsFunded`.
+    ///
+    /// # Panics
+    /// - `"Amount must be positive"` — `amount` is zero or negative.
+    pub fn fund_rewards(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let token = token::Client::new(&env, &storage::get_token(&env));
+        token.transfer(&from, env.current_contract_address(), &amount);
+
+        let pool = storage::get_reward_pool(&env)
+            .checked_add(amount)
+            .expect("Reward pool overflow");
+        storage::set_reward_pool(&env, pool);
+
+        events::RewardsFunded { from, amount }.publish(&env);
+    }
+
+    /// Send `amount` TYC from the unreserved reward pool to the admin (admin
+    /// only). Rewards already promised to open stakes cannot be withdrawn.
+    ///
+    /// Emits `RewardsWithdrawn`.
+    ///
+    /// # Panics
+    /// - `"Amount must be positive"` — `amount` is zero or negative.
+    /// - `"Insufficient reward pool"` — `amount` exceeds the unreserved pool.
+    pub fn withdraw_rewards(env: Env, amount: i128) {
+        let admin = ownership::require_owner(&env);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        let pool = storage::get_reward_pool(&env);
+        if amount > pool {
+            panic!("Insufficient reward pool");
+        }
+        storage::set_reward_pool(&env, pool - amount);
+
+        let token = token::Client::new(&env, &storage::get_token(&env));
+        token.transfer(&env.current_contract_address(), &admin, &amount);
+
+        events::RewardsWithdrawn { to: admin, amount }.publish(&env);
+    }
+
+    // -----------------------------------------------------------------------
+    // Staking
+    // -----------------------------------------------------------------------
+
+    /// Lock `amount` TYC from `player` for `term` seconds. The full reward
+    /// for the term is reserved from the reward pool up front, so it is
+    /// guaranteed once the term ends. Returns the new stake ID.
+    ///
+    /// Emits `Staked`.
+    ///
+    /// # Panics
+    /// - `"Amount must be positive"` — `amount` is zero or negative.
+    /// - `"Unknown staking term"` — `term` is not offered.
+    /// - `"Insufficient reward pool"` — the pool cannot cover the reward.
+    pub fn stake(env: Env, player: Address, amount: i128, term: u64) -> u64 {
+        player.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        let reward_rate_bps =
+            storage::get_term_rate(&env, term).unwrap_or_else(|| panic!("Unknown staking term"));
+
+        let reward = amount
+            .checked_mul(reward_rate_bps as i128)
+            .and_then(|value| value.checked_mul(term as i128))
+            .expect("Arithmetic overflow")
+            / (BPS_DENOMINATOR * SECONDS_PER_YEAR);
+        let pool = storage::get_reward_pool(&env);
+        if reward > pool {
+            panic!("Insufficient reward pool");
+        }
+        storage::set_reward_pool(&env, pool - reward);
+
+        let token = token::Client::new(&env, &storage::get_token(&env));
+        token.transfer(&player, env.current_contract_address(), &amount);
+
+        let stake_id = storage::next_stake_id(&env);
+        storage::add_stake(
+            &env,
+            stake_id,
+            &StakePosition {
+                owner: player.clone(),
+                amount,
+                term,
+                reward_rate_bps,
+                penalty_bps: storage::get_early_unstake_penalty_bps(&env),
+                start: env.ledger().timestamp(),
+                reward,
+            },
+        );
+
+        events::Staked {
+            player,
+            stake_id,
+            amount,
+            term,
+            reward,
+        }
+        .publish(&env);
+        stake_id
+    }
+
+    /// Close stake `stake_id` and pay out `player`, who must own it. Returns
+    /// the amount transferred.
+    ///
+    /// - After the term ends: principal plus the reserved reward.
+    /// - Before the term ends: principal minus the early-unstake penalty fixed
+    ///   when staking. The reward is forfeited; both it and the penalty go
+    ///   back to the reward pool.
+    ///
+    /// Emits `Unstaked`.
+    ///
+    /// # Panics
+    /// - `"Stake not found"` — no open stake has this ID.
+    /// - `"Not the stake owner"` — `player` does not own the stake.
+    pub fn unstake(env: Env, player: Address, stake_id: u64) -> i128 {
+        player.require_auth();
+
+        let stake = storage::get_stake(&env, stake_id).unwrap_or_else(|| panic!("Stake not found"));
+        if stake.owner != player {
+            panic!("Not the stake owner");
+        }
+        storage::remove_stake(&env, stake_id, &stake);
+
+        let (reward, penalty) = if is_matured(&env, &stake) {
+            (stake.reward, 0)
+        } else {
+            let penalty = stake.amount * stake.penalty_bps as i128 / BPS_DENOMINATOR;
+            let pool = storage::get_reward_pool(&env) + stake.reward + penalty;
+            storage::set_reward_pool(&env, pool);
+            (0, penalty)
+        };
+        let payout = stake.amount - penalty + reward;
+
+        if payout > 0 {
+            let token = token::Client::new(&env, &storage::get_token(&env));
+            token.transfer(&env.current_contract_address(), &player, &payout);
+        }
+
+        events::Unstaked {
+            player,
+            stake_id,
+            amount: stake.amount,
+            reward,
+            penalty,
+        }
+        .publish(&env);
+        payout
+    }
+
+    // -----------------------------------------------------------------------
+    // Ownership (two-step, see tycoon_lib::ownership)
+    // -----------------------------------------------------------------------
+
+    /// Propose `new_admin` as the next admin (admin only). Control does not
+    /// move until `new_admin` calls `accept_ownership`.
+    ///
+    /// Emits `OwnershipTransferStarted`.
+    pub fn transfer_ownership(env: Env, new_admin: Address) {
+        ownership::transfer_ownership(&env, &new_admin);
+    }
+
+    /// Complete a pending transfer. Must be signed by the proposed admin.
+    ///
+    /// Emits `OwnershipTransferred`.
+    pub fn accept_ownership(env: Env) {
+        ownership::accept_ownership(&env);
+    }
+
+    /// Withdraw the pending proposal (admin only).
+    pub fn cancel_ownership_transfer(env: Env) {
+        ownership::cancel_ownership_transfer(&env);
+    }
+
+    /// Give up admin rights permanently (admin only). Terms, the penalty and
+    /// the reward pool can no longer be managed; staking keeps working.
+    pub fn renounce_ownership(env: Env) {
+        ownership::renounce_ownership(&env);
+    }
+
+    // -----------------------------------------------------------------------
+    // View functions
+    // -----------------------------------------------------------------------
+
+    /// Returns the current admin.
+    pub fn get_admin(env: Env) -> Address {
+        ownership::get_owner(&env)
+    }
+
+    /// Returns the admin proposed by `transfer_ownership`, if any.
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        ownership::pending_owner(&env)
+    }
+
+    /// Returns the TYC token contract address.
+    pub fn get_token(env: Env) -> Address {
+        storage::get_token(&env)
+    }
+
+    /// Returns the annual reward rate in basis points for `term`, if offered.
+    pub fn get_term_rate(env: Env, term: u64) -> Option<u32> {
+        storage::get_term_rate(&env, term)
+    }
+
+    /// Returns the early-unstake penalty in basis points of the principal.
+    pub fn get_early_unstake_penalty(env: Env) -> u32 {
+        storage::get_early_unstake_penalty_bps(&env)
+    }
+
+    /// Returns the TYC available for new stakes' rewards.
+    pub fn reward_pool(env: Env) -> i128 {
+        storage::get_reward_pool(&env)
+    }
+
+    /// Returns open stake `stake_id`, if any.
+    pub fn get_stake(env: Env, stake_id: u64) -> Option<StakePosition> {
+        storage::get_stake(&env, stake_id)
+    }
+
+    /// Returns the IDs of `player`'s open stakes, oldest first.
+    pub fn stakes_of(env: Env, player: Address) -> Vec<u64> {
+        storage::get_player_stakes(&env, &player)
+    }
+
+    /// Total TYC principal `player` has locked in open stakes. Used by
+    /// tycoon-main-game to gate high-stake lobbies.
+    pub fn staked_balance(env: Env, player: Address) -> i128 {
+        storage::get_staked_balance(&env, &player)
+    }
+
+    /// Reward earned so far by stake `stake_id`, accruing linearly over its
+    /// term. It is only paid if the stake is held until the term ends. Zero
+    /// for unknown stakes.
+    pub fn pending_rewards(env: Env, stake_id: u64) -> i128 {
+        let Some(stake) = storage::get_stake(&env, stake_id) else {
+            return 0;
+        };
+        if is_matured(&env, &stake) {
+            return stake.reward;
+        }
+
+        let elapsed = env.ledger().timestamp() - stake.start;
+        stake.reward * elapsed as i128 / stake.term as i128
+    }
+}
+
+/// Whether `stake`'s term has ended at the current ledger timestamp.
+fn is_matured(env: &Env, stake: &StakePosition) -> bool {
+    env.ledger().timestamp() >= stake.start.saturating_add(stake.term)
+}
@@ -0,0 +1,187 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+// -----------------------------------------------------------------------
+// DataKey
+// -----------------------------------------------------------------------
+
+/// Storage keys for the tycoon-staking contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// The TYC token contract that is staked and paid out as rewards.
+    Token,
+    /// Maps term length in seconds -> annual reward rate in basis points.
+    Term(u64),
+    /// Share of the principal, in basis points, kept on early unstake.
+    EarlyUnstakePenaltyBps,
+    /// TYC available for new rewards. Rewards of open stakes are reserved
+    /// out of it when staking, so they are always covered.
+    RewardPool,
+    /// Auto-incrementing stake ID counter.
+    NextStakeId,
+    /// Maps stake_id -> StakePosition.
+    Stake(u64),
+    /// Maps player -> IDs of the player's open stakes.
+    PlayerStakes(Address),
+    /// Maps player -> sum of the principal of the player's open stakes.
+    StakedBalance(Address),
+}
+
+// -----------------------------------------------------------------------
+// StakePosition struct
+// -----------------------------------------------------------------------
+
+/// A single fixed-term stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakePosition {
+    /// Player who staked and may unstake.
+    pub owner: Address,
+    /// TYC principal locked.
+    pub amount: i128,
+    /// Term length in seconds.
+    pub term: u64,
+    /// Annual reward rate in basis points, fixed when staking.
+    pub reward_rate_bps: u32,
+    /// Early-unstake penalty in basis points, fixed when staking.
+    pub penalty_bps: u32,
+    /// Ledger timestamp the stake was opened.
+    pub start: u64,
+    /// Reward paid on top of the principal once the term has ended.
+    pub reward: i128,
+}
+
+// -----------------------------------------------------------------------
+// Config
+// -----------------------------------------------------------------------
+
+pub fn set_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&DataKey::Token, token);
+}
+
+pub fn get_token(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Token)
+        .expect("Contract not initialized")
+}
+
+pub fn get_term_rate(env: &Env, term: u64) -> Option<u32> {
+    env.storage().persistent().get(&DataKey::Term(term))
+}
+
+pub fn set_term_rate(env: &Env, term: u64, reward_rate_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Term(term), &reward_rate_bps);
+}
+
+pub fn remove_term(env: &Env, term: u64) {
+    env.storage().persistent().remove(&DataKey::Term(term));
+}
+
+pub fn get_early_unstake_penalty_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EarlyUnstakePenaltyBps)
+        .unwrap_or(0)
+}
+
+pub fn set_early_unstake_penalty_bps(env: &Env, penalty_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EarlyUnstakePenaltyBps, &penalty_bps);
+}
+
+pub fn get_reward_pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RewardPool)
+        .unwrap_or(0)
+}
+
+pub fn set_reward_pool(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::RewardPool, &amount);
+}
+
+// -----------------------------------------------------------------------
+// Stakes
+// -----------------------------------------------------------------------
+
+/// Returns the next stake ID and advances the counter. IDs start at 1.
+pub fn next_stake_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextStakeId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextStakeId, &(id + 1));
+    id
+}
+
+pub fn get_stake(env: &Env, stake_id: u64) -> Option<StakePosition> {
+    env.storage().persistent().get(&DataKey::Stake(stake_id))
+}
+
+/// Stores a new stake and adds it to its owner's stakes and staked balance.
+pub fn add_stake(env: &Env, stake_id: u64, stake: &StakePosition) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stake(stake_id), stake);
+
+    let mut ids = get_player_stakes(env, &stake.owner);
+    ids.push_back(stake_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerStakes(stake.owner.clone()), &ids);
+
+    let balance = get_staked_balance(env, &stake.owner)
+        .checked_add(stake.amount)
+        .expect("Staked balance overflow");
+    set_staked_balance(env, &stake.owner, balance);
+}
+
+/// Deletes a stake and removes it from its owner's stakes and staked
+/// balance.
+pub fn remove_stake(env: &Env, stake_id: u64, stake: &StakePosition) {
+    env.storage().persistent().remove(&DataKey::Stake(stake_id));
+
+    let mut ids = get_player_stakes(env, &stake.owner);
+    if let Some(index) = ids.first_index_of(stake_id) {
+        ids.remove(index);
+    }
+    let key = DataKey::PlayerStakes(stake.owner.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    let balance = get_staked_balance(env, &stake.owner) - stake.amount;
+    set_staked_balance(env, &stake.owner, balance);
+}
+
+pub fn get_player_stakes(env: &Env, player: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerStakes(player.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_staked_balance(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakedBalance(player.clone()))
+        .unwrap_or(0)
+}
+
+fn set_staked_balance(env: &Env, player: &Address, balance: i128) {
+    let key = DataKey::StakedBalance(player.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+    }
+}
@@ -0,0 +1,372 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Map, String, Symbol, Val,
+};
+use tycoon_token::{TycoonToken, TycoonTokenClient};
+
+const SUPPLY: i128 = 10_000_000_000_000_000;
+const YEAR: u64 = 365 * 24 * 60 * 60;
+const MONTH: u64 = 30 * 24 * 60 * 60;
+const STAKE: i128 = 1_000_000;
+const POOL: i128 = 500_000;
+
+// -----------------------------------------------------------------------
+// Test helpers
+// -----------------------------------------------------------------------
+
+struct Setup<'a> {
+    env: Env,
+    client: TycoonStakingClient<'a>,
+    token: TycoonTokenClient<'a>,
+    contract_id: Address,
+    admin: Address,
+}
+
+/// Deploys TYC and the staking contract, offers a one-year term at 10% and
+/// funds the reward pool with `POOL` from the admin.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let token_id = env.register(TycoonToken, ());
+    let token = TycoonTokenClient::new(&env, &token_id);
+    token.initialize(
+        &admin,
+        &SUPPLY,
+        &SUPPLY,
+        &7,
+        &String::from_str(&env, "Tycoon"),
+        &String::from_str(&env, "TYC"),
+        &None,
+        &false,
+    );
+
+    let contract_id = env.register(TycoonStaking, ());
+    let client = TycoonStakingClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id);
+    client.set_term(&YEAR, &1_000);
+    client.fund_rewards(&admin, &POOL);
+
+    Setup {
+        env,
+        client,
+        token,
+        contract_id,
+        admin,
+    }
+}
+
+/// Returns a player holding `STAKE` TYC.
+fn funded_player(s: &Setup) -> Address {
+    let player = Address::generate(&s.env);
+    s.token.transfer(&s.admin, &player, &STAKE);
+    player
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + seconds);
+}
+
+// -----------------------------------------------------------------------
+// Initialization and configuration
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_initialize() {
+    let s = setup();
+
+    assert_eq!(s.client.get_admin(), s.admin);
+    assert_eq!(s.client.get_token(), s.token.address);
+    assert_eq!(s.client.get_early_unstake_penalty(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Contract already initialized")]
+fn test_cannot_reinitialize() {
+    let s = setup();
+    s.client
+        .initialize(&Address::generate(&s.env), &s.token.address);
+}
+
+#[test]
+fn test_set_and_remove_term() {
+    let s = setup();
+
+    s.client.set_term(&MONTH, &200);
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    let events = s.env.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "TermSet"), MONTH).into_val(&s.env)
+    );
+    assert_eq!(s.client.get_term_rate(&MONTH), Some(200));
+
+    s.client.remove_term(&MONTH);
+    assert_eq!(s.client.get_term_rate(&MONTH), None);
+}
+
+#[test]
+fn test_configuration_requires_admin_auth() {
+    let s = setup();
+    s.env.set_auths(&[]);
+
+    assert!(s.client.try_set_term(&MONTH, &200).is_err());
+    assert!(s.client.try_remove_term(&YEAR).is_err());
+    assert!(s.client.try_set_early_unstake_penalty(&500).is_err());
+    assert!(s.client.try_withdraw_rewards(&1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Penalty cannot exceed 100%")]
+fn test_penalty_cannot_exceed_principal() {
+    let s = setup();
+    s.client.set_early_unstake_penalty(&10_001);
+}
+
+#[test]
+fn test_fund_and_withdraw_rewards() {
+    let s = setup();
+    assert_eq!(s.client.reward_pool(), POOL);
+    assert_eq!(s.token.balance(&s.contract_id), POOL);
+
+    s.client.withdraw_rewards(&100_000);
+    let events = s.env.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "RewardsWithdrawn"), s.admin.clone()).into_val(&s.env)
+    );
+
+    assert_eq!(s.client.reward_pool(), POOL - 100_000);
+    assert_eq!(s.token.balance(&s.admin), SUPPLY - POOL + 100_000);
+}
+
+// -----------------------------------------------------------------------
+// Staking
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_stake_locks_tokens_and_reserves_reward() {
+    let s = setup();
+    let player = funded_player(&s);
+
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+    assert_eq!(s.env.auths()[0].0, player);
+
+    let events = s.env.events().all();
+    let (emitter, topics, data) = events.last().unwrap();
+    assert_eq!(emitter, s.contract_id);
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "Staked"), player.clone()).into_val(&s.env)
+    );
+    let data: Map<Symbol, Val> = data.into_val(&s.env);
+    let reward: i128 = data
+        .get(Symbol::new(&s.env, "reward"))
+        .unwrap()
+        .into_val(&s.env);
+    assert_eq!(reward, STAKE / 10);
+
+    assert_eq!(stake_id, 1);
+    assert_eq!(s.token.balance(&player), 0);
+    assert_eq!(s.client.staked_balance(&player), STAKE);
+    assert_eq!(s.client.stakes_of(&player), vec![&s.env, stake_id]);
+    assert_eq!(s.client.reward_pool(), POOL - STAKE / 10);
+    assert_eq!(
+        s.client.get_stake(&stake_id),
+        Some(StakePosition {
+            owner: player,
+            amount: STAKE,
+            term: YEAR,
+            reward_rate_bps: 1_000,
+            penalty_bps: 0,
+            start: 1_000,
+            reward: STAKE / 10,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unknown staking term")]
+fn test_stake_rejects_unknown_term() {
+    let s = setup();
+    let player = funded_player(&s);
+    s.client.stake(&player, &STAKE, &MONTH);
+}
+
+#[test]
+#[should_panic(expected = "Amount must be positive")]
+fn test_stake_rejects_zero_amount() {
+    let s = setup();
+    let player = funded_player(&s);
+    s.client.stake(&player, &0, &YEAR);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient reward pool")]
+fn test_stake_requires_funded_pool() {
+    let s = setup();
+    s.client.withdraw_rewards(&POOL);
+    let player = funded_player(&s);
+    s.client.stake(&player, &STAKE, &YEAR);
+}
+
+#[test]
+fn test_pending_rewards_accrue_linearly() {
+    let s = setup();
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+
+    assert_eq!(s.client.pending_rewards(&stake_id), 0);
+    advance(&s.env, YEAR / 4);
+    assert_eq!(s.client.pending_rewards(&stake_id), STAKE / 40);
+    advance(&s.env, YEAR);
+    assert_eq!(s.client.pending_rewards(&stake_id), STAKE / 10);
+    assert_eq!(s.client.pending_rewards(&999), 0);
+}
+
+#[test]
+fn test_unstake_after_term_pays_reward() {
+    let s = setup();
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+
+    advance(&s.env, YEAR);
+    assert_eq!(s.client.unstake(&player, &stake_id), STAKE + STAKE / 10);
+
+    let events = s.env.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "Unstaked"), player.clone()).into_val(&s.env)
+    );
+
+    assert_eq!(s.token.balance(&player), STAKE + STAKE / 10);
+    assert_eq!(s.client.staked_balance(&player), 0);
+    assert!(s.client.stakes_of(&player).is_empty());
+    assert_eq!(s.client.get_stake(&stake_id), None);
+    assert_eq!(s.client.reward_pool(), POOL - STAKE / 10);
+    assert_eq!(s.token.balance(&s.contract_id), POOL - STAKE / 10);
+}
+
+#[test]
+fn test_early_unstake_pays_penalty_into_pool() {
+    let s = setup();
+    s.client.set_early_unstake_penalty(&500);
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+
+    advance(&s.env, YEAR / 2);
+    let penalty = STAKE / 20;
+    assert_eq!(s.client.unstake(&player, &stake_id), STAKE - penalty);
+
+    assert_eq!(s.token.balance(&player), STAKE - penalty);
+    assert_eq!(s.client.staked_balance(&player), 0);
+    // The reserved reward is released and the penalty is added.
+    assert_eq!(s.client.reward_pool(), POOL + penalty);
+    assert_eq!(s.token.balance(&s.contract_id), POOL + penalty);
+}
+
+#[test]
+fn test_penalty_change_does_not_affect_open_stakes() {
+    let s = setup();
+    s.client.set_early_unstake_penalty(&500);
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+
+    s.client.set_early_unstake_penalty(&10_000);
+    let penalty = STAKE / 20;
+    assert_eq!(s.client.unstake(&player, &stake_id), STAKE - penalty);
+    assert_eq!(s.client.reward_pool(), POOL + penalty);
+}
+
+#[test]
+fn test_early_unstake_without_penalty_returns_principal() {
+    let s = setup();
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+
+    assert_eq!(s.client.unstake(&player, &stake_id), STAKE);
+    assert_eq!(s.client.reward_pool(), POOL);
+}
+
+#[test]
+#[should_panic(expected = "Not the stake owner")]
+fn test_only_owner_can_unstake() {
+    let s = setup();
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+    s.client.unstake(&Address::generate(&s.env), &stake_id);
+}
+
+#[test]
+#[should_panic(expected = "Stake not found")]
+fn test_cannot_unstake_twice() {
+    let s = setup();
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+    s.client.unstake(&player, &stake_id);
+    s.client.unstake(&player, &stake_id);
+}
+
+#[test]
+fn test_staked_balance_sums_open_stakes() {
+    let s = setup();
+    s.client.set_term(&MONTH, &100);
+    let player = funded_player(&s);
+
+    let first = s.client.stake(&player, &(STAKE / 2), &YEAR);
+    let second = s.client.stake(&player, &(STAKE / 4), &MONTH);
+    assert_eq!(s.client.staked_balance(&player), STAKE * 3 / 4);
+    assert_eq!(s.client.stakes_of(&player), vec![&s.env, first, second]);
+
+    s.client.unstake(&player, &first);
+    assert_eq!(s.client.staked_balance(&player), STAKE / 4);
+    assert_eq!(s.client.stakes_of(&player), vec![&s.env, second]);
+}
+
+#[test]
+fn test_removed_term_keeps_open_stakes() {
+    let s = setup();
+    let player = funded_player(&s);
+    let stake_id = s.client.stake(&player, &STAKE, &YEAR);
+
+    s.client.remove_term(&YEAR);
+    advance(&s.env, YEAR);
+
+    assert_eq!(s.client.unstake(&player, &stake_id), STAKE + STAKE / 10);
+}
+
+#[test]
+fn test_reserved_rewards_cannot_be_withdrawn() {
+    let s = setup();
+    let player = funded_player(&s);
+    s.client.stake(&player, &STAKE, &YEAR);
+
+    assert!(s.client.try_withdraw_rewards(&POOL).is_err());
+    s.client.withdraw_rewards(&(POOL - STAKE / 10));
+    assert_eq!(s.client.reward_pool(), 0);
+}
+
+// -----------------------------------------------------------------------
+// Ownership
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let s = setup();
+    let new_admin = Address::generate(&s.env);
+
+    s.client.transfer_ownership(&new_admin);
+    assert_eq!(s.client.pending_owner(), Some(new_admin.clone()));
+
+    s.client.accept_ownership();
+    assert_eq!(s.client.get_admin(), new_admin);
+}
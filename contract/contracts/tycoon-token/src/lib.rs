@@ -1,14 +1,26 @@
 #![no_std]
-use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Address, Env, String};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype,
+    token::{self, TokenInterface as _},
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, MuxedAddress, String,
+};
+use tycoon_lib::ownership;
 
-#[contractevent(data_format = "single-value")]
+/// Highest number of decimals accepted at `initialize`.
+const MAX_DECIMALS: u32 = 18;
+
+// Event layouts follow SEP-41 so indexers can treat TYC like any other
+// Stellar token.
+
+#[contractevent(topics = ["mint"], data_format = "single-value")]
 pub struct MintEvent {
     #[topic]
     pub to: Address,
     pub amount: i128,
 }
 
-#[contractevent]
+#[contractevent(topics = ["transfer"], data_format = "single-value")]
 pub struct TransferEvent {
     #[topic]
     pub from: Address,
@@ -17,14 +29,24 @@ pub struct TransferEvent {
     pub amount: i128,
 }
 
-#[contractevent(data_format = "single-value")]
+#[contractevent(topics = ["transfer"])]
+pub struct TransferMuxedEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: u64,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["burn"], data_format = "single-value")]
 pub struct BurnEvent {
     #[topic]
     pub from: Address,
     pub amount: i128,
 }
 
-#[contractevent]
+#[contractevent(topics = ["approve"], data_format = "vec")]
 pub struct ApproveEvent {
     #[topic]
     pub from: Address,
@@ -34,75 +56,606 @@ pub struct ApproveEvent {
     pub expiration_ledger: u32,
 }
 
+#[contractevent(topics = ["minter_added"], data_format = "single-value")]
+pub struct MinterAddedEvent {
+    #[topic]
+    pub minter: Address,
+    pub quota: i128,
+}
+
+#[contractevent(topics = ["minter_quota"], data_format = "single-value")]
+pub struct MinterQuotaUpdatedEvent {
+    #[topic]
+    pub minter: Address,
+    pub quota: i128,
+}
+
+#[contractevent(topics = ["minter_removed"])]
+pub struct MinterRemovedEvent {
+    #[topic]
+    pub minter: Address,
+}
+
+#[contractevent(topics = ["freeze"])]
+pub struct FreezeEvent {
+    #[topic]
+    pub account: Address,
+}
+
+#[contractevent(topics = ["unfreeze"])]
+pub struct UnfreezeEvent {
+    #[topic]
+    pub account: Address,
+}
+
+#[contractevent(topics = ["freezing_disabled"])]
+pub struct FreezingDisabledEvent {}
+
+#[contractevent(topics = ["treasury"])]
+pub struct TreasuryUpdatedEvent {
+    #[topic]
+    pub treasury: Address,
+}
+
+#[contractevent(topics = ["clawback"], data_format = "single-value")]
+pub struct ClawbackEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["pause"])]
+pub struct PauseEvent {
+    #[topic]
+    pub caller: Address,
+}
+
+#[contractevent(topics = ["unpause"])]
+pub struct UnpauseEvent {
+    #[topic]
+    pub caller: Address,
+}
+
+#[contractevent(topics = ["pauser"])]
+pub struct PauserUpdatedEvent {
+    pub pauser: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenMetadata {
+    pub decimal: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// Limits how much the admin can mint within each window of
+/// `period_ledgers` ledgers, counted from the ledger TYC was initialized at.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionSchedule {
+    pub period_ledgers: u32,
+    pub max_per_period: i128,
+}
+
+/// Lifetime mint quota granted to a minter and how much of it is used.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterInfo {
+    pub quota: i128,
+    pub minted: i128,
+}
+
+/// Amount minted so far in emission period `period`.
+#[contracttype]
+#[derive(Clone)]
+pub struct EmissionState {
+    pub period: u32,
+    pub minted: i128,
+}
+
+/// Approval signed off-chain with `owner`'s registered permit key. The XDR
+/// encoding of this struct is the exact message that gets signed; the
+/// network id and token address stop a permit from being replayed on
+/// another network or token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Permit {
+    pub network_id: BytesN<32>,
+    pub token: Address,
+    pub owner: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+    pub nonce: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
+    /// Admin written by releases that predate `tycoon_lib::ownership`. Only
+    /// read by `migrate_admin`.
     Admin,
     Balance(Address),
     Allowance(Address, Address),
     TotalSupply,
     Initialized,
+    Metadata,
+    MaxSupply,
+    EmissionSchedule,
+    EmissionStart,
+    EmissionState,
+    Minter(Address),
+    Freezable,
+    Frozen(Address),
+    Treasury,
+    Paused,
+    Pauser,
+    PermitKey(Address),
+    PermitNonce(Address),
 }
 
 #[contract]
 pub struct TycoonToken;
 
+// `initialize` takes the whole token configuration as separate arguments.
+// `contractimpl` also generates client and argument bindings with the same
+// signature, so the lint is allowed for this module holding only `initialize`.
+mod init {
+    #![allow(clippy::too_many_arguments)]
+
+    use super::*;
+
+    #[contractimpl]
+    impl TycoonToken {
+        /// `max_supply` caps `total_supply` forever and cannot be changed later.
+        /// When `emission_schedule` is set, `mint` is additionally limited to
+        /// `max_per_period` per period; the initial supply does not count
+        /// towards the first period.
+        ///
+        /// `freezable` turns on account freezing and clawback. It can later be
+        /// switched off for good with `disable_freezing`, but never back on.
+        pub fn initialize(
+            e: Env,
+            admin: Address,
+            initial_supply: i128,
+            max_supply: i128,
+            decimal: u32,
+            name: String,
+            symbol: String,
+            emission_schedule: Option<EmissionSchedule>,
+            freezable: bool,
+        ) {
+            if e.storage().instance().has(&DataKey::Initialized) {
+                panic!("Already initialized");
+            }
+            if decimal > MAX_DECIMALS {
+                panic!("Decimal must not be greater than 18");
+            }
+            if max_supply <= 0 {
+                panic!("Max supply must be positive");
+            }
+            if initial_supply < 0 {
+                panic!("Initial supply cannot be negative");
+            }
+            if initial_supply > max_supply {
+                panic!("Initial supply exceeds max supply");
+            }
+            if let Some(schedule) = &emission_schedule {
+                if schedule.period_ledgers == 0 {
+                    panic!("Emission period must be positive");
+                }
+                if schedule.max_per_period <= 0 {
+                    panic!("Emission cap must be positive");
+                }
+                e.storage()
+                    .instance()
+                    .set(&DataKey::EmissionSchedule, schedule);
+                e.storage()
+                    .instance()
+                    .set(&DataKey::EmissionStart, &e.ledger().sequence());
+            }
+            e.storage().instance().set(&DataKey::Initialized, &true);
+            ownership::init_owner(&e, &admin);
+            e.storage().instance().set(&DataKey::MaxSupply, &max_supply);
+            e.storage().instance().set(&DataKey::Freezable, &freezable);
+            e.storage().instance().set(
+                &DataKey::Metadata,
+                &TokenMetadata {
+                    decimal,
+                    name,
+                    symbol,
+                },
+            );
+            e.storage()
+                .instance()
+                .set(&DataKey::TotalSupply, &initial_supply);
+            e.storage()
+                .persistent()
+                .set(&DataKey::Balance(admin.clone()), &initial_supply);
+            MintEvent {
+                to: admin,
+                amount: initial_supply,
+            }
+            .publish(&e);
+        }
+    }
+}
+
 #[contractimpl]
 impl TycoonToken {
-    pub fn initialize(e: Env, admin: Address, initial_supply: i128) {
-        if e.storage().instance().has(&DataKey::Initialized) {
-            panic!("Already initialized");
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        ownership::require_owner(&e);
+
+        mint_tokens(&e, to, amount);
+    }
+
+    // -----------------------------------------------------------------------
+    // Minters
+    // -----------------------------------------------------------------------
+
+    /// Grants `minter` the right to mint up to `quota` TYC in total through
+    /// `minter_mint`. Minters are still bound by the max supply and the
+    /// emission schedule.
+    pub fn add_minter(e: Env, minter: Address, quota: i128) {
+        ownership::require_owner(&e);
+
+        if quota < 0 {
+            panic!("Quota cannot be negative");
+        }
+        let key = DataKey::Minter(minter.clone());
+        if e.storage().persistent().has(&key) {
+            panic!("Already a minter");
         }
-        e.storage().instance().set(&DataKey::Initialized, &true);
-        e.storage().instance().set(&DataKey::Admin, &admin);
         e.storage()
-            .instance()
-            .set(&DataKey::TotalSupply, &initial_supply);
+            .persistent()
+            .set(&key, &MinterInfo { quota, minted: 0 });
+
+        MinterAddedEvent { minter, quota }.publish(&e);
+    }
+
+    /// Replaces a minter's total quota. Amounts already minted still count
+    /// against the new quota.
+    pub fn set_minter_quota(e: Env, minter: Address, quota: i128) {
+        ownership::require_owner(&e);
+
+        if quota < 0 {
+            panic!("Quota cannot be negative");
+        }
+        let mut info = read_minter(&e, &minter).expect("Not a minter");
+        info.quota = quota;
         e.storage()
             .persistent()
-            .set(&DataKey::Balance(admin.clone()), &initial_supply);
-        MintEvent {
-            to: admin,
-            amount: initial_supply,
+            .set(&DataKey::Minter(minter.clone()), &info);
+
+        MinterQuotaUpdatedEvent { minter, quota }.publish(&e);
+    }
+
+    pub fn remove_minter(e: Env, minter: Address) {
+        ownership::require_owner(&e);
+
+        let key = DataKey::Minter(minter.clone());
+        if !e.storage().persistent().has(&key) {
+            panic!("Not a minter");
         }
-        .publish(&e);
+        e.storage().persistent().remove(&key);
+
+        MinterRemovedEvent { minter }.publish(&e);
     }
 
-    pub fn mint(e: Env, to: Address, amount: i128) {
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Mints on behalf of a minter, drawing down its quota.
+    pub fn minter_mint(e: Env, minter: Address, to: Address, amount: i128) {
+        minter.require_auth();
+
+        let mut info = read_minter(&e, &minter).expect("Unauthorized: not a minter");
+        if amount > info.quota - info.minted {
+            panic!("Minter quota exceeded");
+        }
+        mint_tokens(&e, to, amount);
+
+        info.minted += amount;
+        e.storage()
+            .persistent()
+            .set(&DataKey::Minter(minter), &info);
+    }
+
+    pub fn is_minter(e: Env, minter: Address) -> bool {
+        e.storage().persistent().has(&DataKey::Minter(minter))
+    }
+
+    pub fn minter_info(e: Env, minter: Address) -> Option<MinterInfo> {
+        read_minter(&e, &minter)
+    }
+
+    /// Amount `minter` can still mint under its quota (0 for non-minters).
+    pub fn minter_remaining_quota(e: Env, minter: Address) -> i128 {
+        read_minter(&e, &minter)
+            .map(|info| (info.quota - info.minted).max(0))
+            .unwrap_or(0)
+    }
+
+    // -----------------------------------------------------------------------
+    // Freezing
+    // -----------------------------------------------------------------------
+
+    /// Blocks `account` from sending, receiving, having tokens spent by
+    /// `transfer_from`/`burn_from`, and being minted to.
+    pub fn freeze(e: Env, account: Address) {
+        ownership::require_owner(&e);
+        require_freezable(&e);
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::Frozen(account.clone()), &true);
+
+        FreezeEvent { account }.publish(&e);
+    }
+
+    pub fn unfreeze(e: Env, account: Address) {
+        ownership::require_owner(&e);
+
+        let key = DataKey::Frozen(account.clone());
+        if !e.storage().persistent().has(&key) {
+            panic!("Account is not frozen");
+        }
+        e.storage().persistent().remove(&key);
+
+        UnfreezeEvent { account }.publish(&e);
+    }
+
+    /// Turns freezing and clawback off permanently. Accounts that are still
+    /// frozen are released.
+    pub fn disable_freezing(e: Env) {
+        ownership::require_owner(&e);
+        require_freezable(&e);
+
+        e.storage().instance().set(&DataKey::Freezable, &false);
+
+        FreezingDisabledEvent {}.publish(&e);
+    }
+
+    /// Sets where `clawback` sends seized balances.
+    pub fn set_treasury(e: Env, treasury: Address) {
+        ownership::require_owner(&e);
 
+        e.storage().instance().set(&DataKey::Treasury, &treasury);
+
+        TreasuryUpdatedEvent { treasury }.publish(&e);
+    }
+
+    /// Moves `amount` from a frozen account to the treasury. Total supply is
+    /// unchanged.
+    pub fn clawback(e: Env, from: Address, amount: i128) {
+        ownership::require_owner(&e);
+        require_freezable(&e);
+
+        if !is_frozen(&e, &from) {
+            panic!("Account is not frozen");
+        }
         if amount <= 0 {
             panic!("Amount must be positive");
         }
+        let treasury: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .expect("Treasury not set");
 
-        let balance: i128 = e
+        move_balance(&e, &from, &treasury, amount);
+
+        ClawbackEvent {
+            from,
+            treasury,
+            amount,
+        }
+        .publish(&e);
+    }
+
+    pub fn is_freezable(e: Env) -> bool {
+        is_freezable(&e)
+    }
+
+    /// Always `false` once freezing has been disabled.
+    pub fn is_frozen(e: Env, account: Address) -> bool {
+        is_frozen(&e, &account)
+    }
+
+    pub fn treasury(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::Treasury)
+    }
+
+    // -----------------------------------------------------------------------
+    // Pausing
+    // -----------------------------------------------------------------------
+
+    /// Sets (or with `None`, clears) the address that may pause and unpause
+    /// alongside the admin.
+    pub fn set_pauser(e: Env, pauser: Option<Address>) {
+        ownership::require_owner(&e);
+
+        match &pauser {
+            Some(pauser) => e.storage().instance().set(&DataKey::Pauser, pauser),
+            None => e.storage().instance().remove(&DataKey::Pauser),
+        }
+
+        PauserUpdatedEvent { pauser }.publish(&e);
+    }
+
+    /// Halts transfers, mints and burns. Views and `approve` keep working.
+    pub fn pause(e: Env, caller: Address) {
+        require_pause_authority(&e, &caller);
+        if is_paused(&e) {
+            panic!("Token is paused");
+        }
+
+        e.storage().instance().set(&DataKey::Paused, &true);
+
+        PauseEvent { caller }.publish(&e);
+    }
+
+    pub fn unpause(e: Env, caller: Address) {
+        require_pause_authority(&e, &caller);
+        if !is_paused(&e) {
+            panic!("Token is not paused");
+        }
+
+        e.storage().instance().set(&DataKey::Paused, &false);
+
+        UnpauseEvent { caller }.publish(&e);
+    }
+
+    pub fn is_paused(e: Env) -> bool {
+        is_paused(&e)
+    }
+
+    pub fn pauser(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::Pauser)
+    }
+
+    // -----------------------------------------------------------------------
+    // Permits
+    // -----------------------------------------------------------------------
+
+    /// Registers (or with `None`, removes) the ed25519 public key whose
+    /// signatures `permit` accepts for `owner`. A registered key is used
+    /// instead of the account's master key, which may not be one of its
+    /// signers.
+    pub fn set_permit_key(e: Env, owner: Address, public_key: Option<BytesN<32>>) {
+        owner.require_auth();
+
+        let key = DataKey::PermitKey(owner);
+        match &public_key {
+            Some(public_key) => e.storage().persistent().set(&key, public_key),
+            None => e.storage().persistent().remove(&key),
+        }
+    }
+
+    pub fn permit_key(e: Env, owner: Address) -> Option<BytesN<32>> {
+        e.storage().persistent().get(&DataKey::PermitKey(owner))
+    }
+
+    /// Same as `approve`, but authorized by an ed25519 `signature` over
+    /// `permit_payload(...)` instead of `owner`'s auth, so anyone (e.g. a
+    /// relayer) can submit it. `owner` must have registered a key with
+    /// `set_permit_key`. `nonce` must equal `permit_nonce(owner)` and is
+    /// consumed on success.
+    pub fn permit(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) {
+        if amount < 0 {
+            panic!("Amount cannot be negative");
+        }
+        let public_key: BytesN<32> = e
             .storage()
             .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        let new_balance = balance.checked_add(amount).expect("Balance overflow");
+            .get(&DataKey::PermitKey(owner.clone()))
+            .expect("Permit key not set");
+        let expected_nonce = read_permit_nonce(&e, &owner);
+        if nonce != expected_nonce {
+            panic!("Invalid nonce");
+        }
+
+        let payload = permit_payload(&e, &owner, &spender, amount, expiration_ledger, nonce);
+        e.crypto().ed25519_verify(&public_key, &payload, &signature);
+
         e.storage()
             .persistent()
-            .set(&DataKey::Balance(to.clone()), &new_balance);
+            .set(&DataKey::PermitNonce(owner.clone()), &(nonce + 1));
+        write_allowance(&e, &owner, &spender, amount, expiration_ledger);
 
-        let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
-        e.storage().instance().set(
-            &DataKey::TotalSupply,
-            &supply.checked_add(amount).expect("Supply overflow"),
-        );
+        ApproveEvent {
+            from: owner,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&e);
+    }
 
-        MintEvent { to, amount }.publish(&e);
+    /// Next nonce `owner` must sign a permit with.
+    pub fn permit_nonce(e: Env, owner: Address) -> u64 {
+        read_permit_nonce(&e, &owner)
     }
 
-    pub fn set_admin(e: Env, new_admin: Address) {
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        e.storage().instance().set(&DataKey::Admin, &new_admin);
+    /// Bytes the owner has to sign for `permit` on this network and token.
+    pub fn permit_payload(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+    ) -> Bytes {
+        permit_payload(&e, &owner, &spender, amount, expiration_ledger, nonce)
     }
 
     pub fn admin(e: Env) -> Address {
-        e.storage().instance().get(&DataKey::Admin).unwrap()
+        ownership::get_owner(&e)
+    }
+
+    /// Moves the admin stored by a pre-ownership release into
+    /// `tycoon_lib::ownership`. Run once after upgrading such a deployment;
+    /// requires the legacy admin's authorization.
+    ///
+    /// # Panics
+    /// - `"Admin already migrated"` if the owner is already set.
+    /// - `"No legacy admin"` if the old admin key is absent.
+    pub fn migrate_admin(e: Env) -> Address {
+        if ownership::is_owner_set(&e) {
+            panic!("Admin already migrated");
+        }
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("No legacy admin");
+        admin.require_auth();
+
+        ownership::init_owner(&e, &admin);
+        e.storage().instance().remove(&DataKey::Admin);
+        admin
+    }
+
+    // -----------------------------------------------------------------------
+    // Admin transfer (two-step, see tycoon_lib::ownership)
+    // -----------------------------------------------------------------------
+
+    /// Proposes `new_admin`; nothing changes until they call `accept_ownership`.
+    pub fn transfer_ownership(e: Env, new_admin: Address) {
+        ownership::transfer_ownership(&e, &new_admin);
+    }
+
+    pub fn accept_ownership(e: Env) {
+        ownership::accept_ownership(&e);
+    }
+
+    pub fn cancel_ownership_transfer(e: Env) {
+        ownership::cancel_ownership_transfer(&e);
+    }
+
+    /// Permanently removes the admin. Minting, minter management and admin
+    /// transfers are disabled afterwards.
+    pub fn renounce_ownership(e: Env) {
+        ownership::renounce_ownership(&e);
+    }
+
+    pub fn pending_owner(e: Env) -> Option<Address> {
+        ownership::pending_owner(&e)
     }
 
     pub fn total_supply(e: Env) -> i128 {
@@ -111,25 +664,48 @@ impl TycoonToken {
             .get(&DataKey::TotalSupply)
             .unwrap_or(0)
     }
+
+    pub fn max_supply(e: Env) -> i128 {
+        read_max_supply(&e)
+    }
+
+    /// How much can still be minted before `max_supply` is reached. Burning
+    /// tokens frees up room under the cap again.
+    pub fn remaining_mintable_supply(e: Env) -> i128 {
+        read_max_supply(&e) - Self::total_supply(e)
+    }
+
+    pub fn emission_schedule(e: Env) -> Option<EmissionSchedule> {
+        read_emission_schedule(&e)
+    }
+
+    /// How much the admin can mint right now: the unused part of the current
+    /// emission period, bounded by the remaining supply. Without a schedule
+    /// this equals `remaining_mintable_supply`.
+    pub fn current_period_allowance(e: Env) -> i128 {
+        let remaining = Self::remaining_mintable_supply(e.clone());
+        match read_emission_schedule(&e) {
+            Some(schedule) => {
+                let state = read_emission_state(&e, &schedule);
+                remaining.min(schedule.max_per_period - state.minted)
+            }
+            None => remaining,
+        }
+    }
 }
 
 #[contractimpl]
-impl TycoonToken {
-    pub fn allowance(e: Env, from: Address, spender: Address) -> i128 {
-        e.storage()
-            .persistent()
-            .get(&DataKey::Allowance(from, spender))
-            .unwrap_or(0)
+impl token::TokenInterface for TycoonToken {
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&e, &from, &spender).amount
     }
 
-    pub fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
         from.require_auth();
         if amount < 0 {
             panic!("Amount cannot be negative");
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Allowance(from.clone(), spender.clone()), &amount);
+        write_allowance(&e, &from, &spender, amount, expiration_ledger);
         ApproveEvent {
             from,
             spender,
@@ -139,98 +715,67 @@ impl TycoonToken {
         .publish(&e);
     }
 
-    pub fn balance(e: Env, id: Address) -> i128 {
+    fn balance(e: Env, id: Address) -> i128 {
         e.storage()
             .persistent()
             .get(&DataKey::Balance(id))
             .unwrap_or(0)
     }
 
-    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+    fn transfer(e: Env, from: Address, to_muxed: MuxedAddress, amount: i128) {
+        let to = to_muxed.address();
         from.require_auth();
         if amount < 0 {
             panic!("Amount cannot be negative");
         }
+        ensure_not_paused(&e);
+        ensure_not_frozen(&e, &from);
+        ensure_not_frozen(&e, &to);
         if amount == 0 {
             return;
         }
 
-        let from_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(from.clone()))
-            .unwrap_or(0);
-        if from_balance < amount {
-            panic!("Insufficient balance");
-        }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
-
-        let to_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        e.storage().persistent().set(
-            &DataKey::Balance(to.clone()),
-            &to_balance.checked_add(amount).expect("Balance overflow"),
-        );
+        move_balance(&e, &from, &to, amount);
 
-        TransferEvent { from, to, amount }.publish(&e);
+        match to_muxed.id() {
+            Some(to_muxed_id) => TransferMuxedEvent {
+                from,
+                to,
+                to_muxed_id,
+                amount,
+            }
+            .publish(&e),
+            None => TransferEvent { from, to, amount }.publish(&e),
+        }
     }
 
-    pub fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
         spender.require_auth();
         if amount < 0 {
             panic!("Amount cannot be negative");
         }
+        ensure_not_paused(&e);
+        ensure_not_frozen(&e, &from);
+        ensure_not_frozen(&e, &to);
         if amount == 0 {
             return;
         }
 
-        let allowance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Allowance(from.clone(), spender.clone()))
-            .unwrap_or(0);
-        if allowance < amount {
-            panic!("Insufficient allowance");
-        }
-        e.storage().persistent().set(
-            &DataKey::Allowance(from.clone(), spender),
-            &(allowance - amount),
-        );
+        spend_allowance(&e, &from, &spender, amount);
 
-        let from_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(from.clone()))
-            .unwrap_or(0);
-        if from_balance < amount {
-            panic!("Insufficient balance");
-        }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
-
-        let to_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        e.storage().persistent().set(
-            &DataKey::Balance(to.clone()),
-            &to_balance.checked_add(amount).expect("Balance overflow"),
-        );
+        move_balance(&e, &from, &to, amount);
 
         TransferEvent { from, to, amount }.publish(&e);
     }
 
-    pub fn burn(e: Env, from: Address, amount: i128) {
+    fn burn(e: Env, from: Address, amount: i128) {
         from.require_auth();
-        if amount <= 0 {
-            panic!("Amount must be positive");
+        if amount < 0 {
+            panic!("Amount cannot be negative");
+        }
+        ensure_not_paused(&e);
+        if amount == 0 {
+            return;
         }
 
         let balance: i128 = e
@@ -253,24 +798,18 @@ impl TycoonToken {
         BurnEvent { from, amount }.publish(&e);
     }
 
-    pub fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
         spender.require_auth();
-        if amount <= 0 {
-            panic!("Amount must be positive");
+        if amount < 0 {
+            panic!("Amount cannot be negative");
         }
-
-        let allowance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Allowance(from.clone(), spender.clone()))
-            .unwrap_or(0);
-        if allowance < amount {
-            panic!("Insufficient allowance");
+        ensure_not_paused(&e);
+        ensure_not_frozen(&e, &from);
+        if amount == 0 {
+            return;
         }
-        e.storage().persistent().set(
-            &DataKey::Allowance(from.clone(), spender),
-            &(allowance - amount),
-        );
+
+        spend_allowance(&e, &from, &spender, amount);
 
         let balance: i128 = e
             .storage()
@@ -292,18 +831,262 @@ impl TycoonToken {
         BurnEvent { from, amount }.publish(&e);
     }
 
-    pub fn decimals(_e: Env) -> u32 {
-        18
+    fn decimals(e: Env) -> u32 {
+        read_metadata(&e).decimal
+    }
+
+    fn name(e: Env) -> String {
+        read_metadata(&e).name
+    }
+
+    fn symbol(e: Env) -> String {
+        read_metadata(&e).symbol
+    }
+}
+
+fn mint_tokens(e: &Env, to: Address, amount: i128) {
+    if amount <= 0 {
+        panic!("Amount must be positive");
+    }
+    ensure_not_paused(e);
+    ensure_not_frozen(e, &to);
+
+    let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
+    let new_supply = supply.checked_add(amount).expect("Supply overflow");
+    if new_supply > read_max_supply(e) {
+        panic!("Max supply exceeded");
+    }
+
+    if let Some(schedule) = read_emission_schedule(e) {
+        let mut state = read_emission_state(e, &schedule);
+        if amount > schedule.max_per_period - state.minted {
+            panic!("Emission cap exceeded for current period");
+        }
+        state.minted += amount;
+        e.storage().instance().set(&DataKey::EmissionState, &state);
+    }
+
+    let balance: i128 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Balance(to.clone()))
+        .unwrap_or(0);
+    let new_balance = balance.checked_add(amount).expect("Balance overflow");
+    e.storage()
+        .persistent()
+        .set(&DataKey::Balance(to.clone()), &new_balance);
+    e.storage()
+        .instance()
+        .set(&DataKey::TotalSupply, &new_supply);
+
+    MintEvent { to, amount }.publish(e);
+}
+
+fn move_balance(e: &Env, from: &Address, to: &Address, amount: i128) {
+    let from_balance: i128 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Balance(from.clone()))
+        .unwrap_or(0);
+    if from_balance < amount {
+        panic!("Insufficient balance");
+    }
+    e.storage()
+        .persistent()
+        .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
+
+    let to_balance: i128 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Balance(to.clone()))
+        .unwrap_or(0);
+    e.storage().persistent().set(
+        &DataKey::Balance(to.clone()),
+        &to_balance.checked_add(amount).expect("Balance overflow"),
+    );
+}
+
+fn is_paused(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+fn ensure_not_paused(e: &Env) {
+    if is_paused(e) {
+        panic!("Token is paused");
+    }
+}
+
+/// Requires `caller` to be the admin or the pauser and to have signed.
+fn require_pause_authority(e: &Env, caller: &Address) {
+    caller.require_auth();
+    let is_admin = ownership::owner(e).is_some_and(|admin| admin == *caller);
+    let is_pauser = e
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Pauser)
+        .is_some_and(|pauser| pauser == *caller);
+    if !is_admin && !is_pauser {
+        panic!("Unauthorized: only admin or pauser can pause");
+    }
+}
+
+fn is_freezable(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::Freezable)
+        .unwrap_or(false)
+}
+
+fn require_freezable(e: &Env) {
+    if !is_freezable(e) {
+        panic!("Freezing is disabled");
+    }
+}
+
+fn is_frozen(e: &Env, account: &Address) -> bool {
+    is_freezable(e)
+        && e.storage()
+            .persistent()
+            .has(&DataKey::Frozen(account.clone()))
+}
+
+fn ensure_not_frozen(e: &Env, account: &Address) {
+    if is_frozen(e, account) {
+        panic!("Account is frozen");
+    }
+}
+
+fn read_minter(e: &Env, minter: &Address) -> Option<MinterInfo> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Minter(minter.clone()))
+}
+
+fn read_metadata(e: &Env) -> TokenMetadata {
+    e.storage()
+        .instance()
+        .get(&DataKey::Metadata)
+        .expect("Not initialized")
+}
+
+fn read_max_supply(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::MaxSupply)
+        .expect("Not initialized")
+}
+
+fn read_emission_schedule(e: &Env) -> Option<EmissionSchedule> {
+    e.storage().instance().get(&DataKey::EmissionSchedule)
+}
+
+/// Returns the emission state for the current period, starting a fresh one
+/// once the ledger has moved past the period that was last minted in.
+fn read_emission_state(e: &Env, schedule: &EmissionSchedule) -> EmissionState {
+    let start: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::EmissionStart)
+        .unwrap_or(0);
+    let period = (e.ledger().sequence() - start) / schedule.period_ledgers;
+    match e
+        .storage()
+        .instance()
+        .get::<_, EmissionState>(&DataKey::EmissionState)
+    {
+        Some(state) if state.period == period => state,
+        _ => EmissionState { period, minted: 0 },
+    }
+}
+
+fn read_permit_nonce(e: &Env, owner: &Address) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::PermitNonce(owner.clone()))
+        .unwrap_or(0)
+}
+
+fn permit_payload(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> Bytes {
+    Permit {
+        network_id: e.ledger().network_id(),
+        token: e.current_contract_address(),
+        owner: owner.clone(),
+        spender: spender.clone(),
+        amount,
+        expiration_ledger,
+        nonce,
     }
+    .to_xdr(e)
+}
 
-    pub fn name(e: Env) -> String {
-        String::from_str(&e, "Tycoon")
+fn read_allowance(e: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    match e.storage().temporary().get::<_, AllowanceValue>(&key) {
+        Some(allowance) if allowance.expiration_ledger < e.ledger().sequence() => AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+        Some(allowance) => allowance,
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
     }
+}
 
-    pub fn symbol(e: Env) -> String {
-        String::from_str(&e, "TYC")
+fn write_allowance(
+    e: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < e.ledger().sequence() {
+        panic!("Expiration ledger is in the past");
+    }
+
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    e.storage().temporary().set(
+        &key,
+        &AllowanceValue {
+            amount,
+            expiration_ledger,
+        },
+    );
+
+    // Keep the entry alive exactly until it expires
+    if amount > 0 {
+        let live_for = expiration_ledger - e.ledger().sequence();
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
     }
 }
 
+fn spend_allowance(e: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = read_allowance(e, from, spender);
+    if allowance.amount < amount {
+        panic!("Insufficient allowance");
+    }
+    write_allowance(
+        e,
+        from,
+        spender,
+        allowance.amount - amount,
+        allowance.expiration_ledger,
+    );
+}
+
 #[cfg(test)]
 mod test;
+
+#[cfg(test)]
+mod sep41_test;
@@ -0,0 +1,371 @@
+//! SEP-41 conformance suite.
+//!
+//! Every check is written against the SDK's generic `token::TokenClient` and
+//! runs twice: once against `TycoonToken` and once against the Stellar Asset
+//! Contract, so TYC can be swapped for any SEP-41 token in our other contracts.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger, MuxedAddress as _},
+    token::{StellarAssetClient, TokenClient},
+    Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+const FUNDED: i128 = 1_000_000;
+
+/// Deploys TYC with 7 decimals and returns (token, holder funded with `FUNDED`).
+fn deploy_tyc(e: &Env) -> (Address, Address) {
+    let holder = Address::generate(e);
+    let contract_id = e.register(TycoonToken, ());
+    TycoonTokenClient::new(e, &contract_id).initialize(
+        &holder,
+        &FUNDED,
+        &FUNDED,
+        &7,
+        &String::from_str(e, "Tycoon"),
+        &String::from_str(e, "TYC"),
+        &None,
+        &false,
+    );
+    (contract_id, holder)
+}
+
+/// Deploys a Stellar Asset Contract and returns (token, holder funded with `FUNDED`).
+fn deploy_sac(e: &Env) -> (Address, Address) {
+    let holder = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    StellarAssetClient::new(e, &sac.address()).mint(&holder, &FUNDED);
+    (sac.address(), holder)
+}
+
+/// Asserts the most recent event was emitted by `token`, starts with `topics`
+/// and carries `data`. Trailing topics (the SAC appends its asset name) are
+/// ignored.
+fn assert_last_event(e: &Env, token: &Address, topics: Vec<Val>, data: Val) {
+    let events = e.events().all();
+    let (contract, event_topics, event_data) = events.last().expect("No event emitted");
+    assert_eq!(&contract, token);
+    assert!(event_topics.len() >= topics.len());
+    assert_eq!(event_topics.slice(0..topics.len()), topics);
+    assert_eq!(
+        i128::try_from_val(e, &event_data).ok(),
+        i128::try_from_val(e, &data).ok()
+    );
+    assert_eq!(
+        Vec::<Val>::try_from_val(e, &event_data)
+            .map(|v| v.len())
+            .ok(),
+        Vec::<Val>::try_from_val(e, &data).map(|v| v.len()).ok()
+    );
+}
+
+// -----------------------------------------------------------------------
+// Checks
+// -----------------------------------------------------------------------
+
+fn decimals_is_seven(e: &Env, token: &Address, _holder: &Address) {
+    assert_eq!(TokenClient::new(e, token).decimals(), 7);
+}
+
+fn metadata_is_readable(e: &Env, token: &Address, _holder: &Address) {
+    let client = TokenClient::new(e, token);
+    assert!(!client.name().is_empty());
+    assert!(!client.symbol().is_empty());
+}
+
+fn unknown_balance_is_zero(e: &Env, token: &Address, _holder: &Address) {
+    assert_eq!(TokenClient::new(e, token).balance(&Address::generate(e)), 0);
+}
+
+fn transfer_moves_balance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let to = Address::generate(e);
+
+    client.transfer(holder, &to, &400);
+    assert_last_event(
+        e,
+        token,
+        (symbol_short!("transfer"), holder.clone(), to.clone()).into_val(e),
+        400_i128.into_val(e),
+    );
+
+    assert_eq!(e.auths()[0].0, holder.clone());
+    assert_eq!(client.balance(holder), FUNDED - 400);
+    assert_eq!(client.balance(&to), 400);
+}
+
+fn transfer_to_self_keeps_balance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    client.transfer(holder, holder, &100);
+    assert_eq!(client.balance(holder), FUNDED);
+}
+
+fn transfer_zero_is_allowed(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let to = Address::generate(e);
+    client.transfer(holder, &to, &0);
+    assert_eq!(client.balance(holder), FUNDED);
+    assert_eq!(client.balance(&to), 0);
+}
+
+fn transfer_over_balance_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let to = Address::generate(e);
+    assert!(client.try_transfer(holder, &to, &(FUNDED + 1)).is_err());
+    assert_eq!(client.balance(holder), FUNDED);
+    assert_eq!(client.balance(&to), 0);
+}
+
+fn transfer_negative_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    assert!(client
+        .try_transfer(holder, Address::generate(e), &-1)
+        .is_err());
+}
+
+fn approve_sets_allowance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &500, &expiration);
+    assert_last_event(
+        e,
+        token,
+        (Symbol::new(e, "approve"), holder.clone(), spender.clone()).into_val(e),
+        (500_i128, expiration).into_val(e),
+    );
+
+    assert_eq!(e.auths()[0].0, holder.clone());
+    assert_eq!(client.allowance(holder, &spender), 500);
+}
+
+fn approve_overwrites_allowance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &500, &expiration);
+    client.approve(holder, &spender, &200, &expiration);
+
+    assert_eq!(client.allowance(holder, &spender), 200);
+}
+
+fn unknown_allowance_is_zero(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    assert_eq!(client.allowance(holder, &Address::generate(e)), 0);
+}
+
+fn allowance_expires(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 10;
+
+    client.approve(holder, &spender, &500, &expiration);
+    e.ledger().set_sequence_number(expiration + 1);
+
+    assert_eq!(client.allowance(holder, &spender), 0);
+    assert!(client
+        .try_transfer_from(&spender, holder, &spender, &1)
+        .is_err());
+}
+
+fn approve_in_past_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    e.ledger().set_sequence_number(100);
+    assert!(client
+        .try_approve(holder, &Address::generate(e), &500, &99)
+        .is_err());
+}
+
+fn approve_negative_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let expiration = e.ledger().sequence() + 100;
+    assert!(client
+        .try_approve(holder, &Address::generate(e), &-1, &expiration)
+        .is_err());
+}
+
+fn transfer_from_spends_allowance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let to = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &500, &expiration);
+    client.transfer_from(&spender, holder, &to, &300);
+    assert_last_event(
+        e,
+        token,
+        (symbol_short!("transfer"), holder.clone(), to.clone()).into_val(e),
+        300_i128.into_val(e),
+    );
+
+    assert_eq!(e.auths()[0].0, spender);
+    assert_eq!(client.allowance(holder, &spender), 200);
+    assert_eq!(client.balance(holder), FUNDED - 300);
+    assert_eq!(client.balance(&to), 300);
+}
+
+fn transfer_from_over_allowance_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &500, &expiration);
+
+    assert!(client
+        .try_transfer_from(&spender, holder, &spender, &501)
+        .is_err());
+    assert_eq!(client.allowance(holder, &spender), 500);
+}
+
+fn transfer_from_over_balance_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &(FUNDED * 2), &expiration);
+
+    assert!(client
+        .try_transfer_from(&spender, holder, &spender, &(FUNDED + 1))
+        .is_err());
+    assert_eq!(client.balance(holder), FUNDED);
+}
+
+fn burn_reduces_balance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+
+    client.burn(holder, &100);
+    assert_last_event(
+        e,
+        token,
+        (symbol_short!("burn"), holder.clone()).into_val(e),
+        100_i128.into_val(e),
+    );
+
+    assert_eq!(e.auths()[0].0, holder.clone());
+    assert_eq!(client.balance(holder), FUNDED - 100);
+}
+
+fn burn_over_balance_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    assert!(client.try_burn(holder, &(FUNDED + 1)).is_err());
+    assert_eq!(client.balance(holder), FUNDED);
+}
+
+fn burn_negative_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    assert!(client.try_burn(holder, &-1).is_err());
+}
+
+fn burn_from_spends_allowance(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &500, &expiration);
+    client.burn_from(&spender, holder, &200);
+    assert_last_event(
+        e,
+        token,
+        (symbol_short!("burn"), holder.clone()).into_val(e),
+        200_i128.into_val(e),
+    );
+
+    assert_eq!(e.auths()[0].0, spender);
+    assert_eq!(client.allowance(holder, &spender), 300);
+    assert_eq!(client.balance(holder), FUNDED - 200);
+}
+
+fn burn_from_over_allowance_fails(e: &Env, token: &Address, holder: &Address) {
+    let client = TokenClient::new(e, token);
+    let spender = Address::generate(e);
+    let expiration = e.ledger().sequence() + 100;
+
+    client.approve(holder, &spender, &100, &expiration);
+
+    assert!(client.try_burn_from(&spender, holder, &101).is_err());
+    assert_eq!(client.balance(holder), FUNDED);
+}
+
+// -----------------------------------------------------------------------
+// Test generation
+// -----------------------------------------------------------------------
+
+macro_rules! conformance {
+    ($($check:ident),* $(,)?) => {
+        mod tyc {
+            $(
+                #[test]
+                fn $check() {
+                    let e = super::Env::default();
+                    e.mock_all_auths();
+                    let (token, holder) = super::deploy_tyc(&e);
+                    super::$check(&e, &token, &holder);
+                }
+            )*
+        }
+
+        mod sac {
+            $(
+                #[test]
+                fn $check() {
+                    let e = super::Env::default();
+                    e.mock_all_auths();
+                    let (token, holder) = super::deploy_sac(&e);
+                    super::$check(&e, &token, &holder);
+                }
+            )*
+        }
+    };
+}
+
+conformance!(
+    decimals_is_seven,
+    metadata_is_readable,
+    unknown_balance_is_zero,
+    transfer_moves_balance,
+    transfer_to_self_keeps_balance,
+    transfer_zero_is_allowed,
+    transfer_over_balance_fails,
+    transfer_negative_fails,
+    approve_sets_allowance,
+    approve_overwrites_allowance,
+    unknown_allowance_is_zero,
+    allowance_expires,
+    approve_in_past_fails,
+    approve_negative_fails,
+    transfer_from_spends_allowance,
+    transfer_from_over_allowance_fails,
+    transfer_from_over_balance_fails,
+    burn_reduces_balance,
+    burn_over_balance_fails,
+    burn_negative_fails,
+    burn_from_spends_allowance,
+    burn_from_over_allowance_fails,
+);
+
+// Muxed destinations are accounts, and the SAC rejects accounts without a
+// trustline, so this one only runs against TYC.
+#[test]
+fn transfer_to_muxed_credits_address() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (token, holder) = deploy_tyc(&e);
+    let client = TokenClient::new(&e, &token);
+    let muxed = MuxedAddress::new(MuxedAddress::generate(&e), 42);
+    let to = muxed.address();
+
+    client.transfer(&holder, &muxed, &250);
+    let (_, topics, _) = e.events().all().last().unwrap();
+    assert!(topics
+        .get(0)
+        .unwrap()
+        .shallow_eq(&symbol_short!("transfer").to_val()));
+
+    assert_eq!(client.balance(&to), 250);
+}
@@ -1,7 +1,39 @@
+extern crate std;
+
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Env,
+};
+
+const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000;
+const MAX_SUPPLY: i128 = INITIAL_SUPPLY * 10;
+const DECIMALS: u32 = 7;
+
+fn initialize(e: &Env, client: &TycoonTokenClient, admin: &Address, initial_supply: i128) {
+    initialize_capped(e, client, admin, initial_supply, MAX_SUPPLY, None);
+}
 
-const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000; // 1e9 * 10^18
+fn initialize_capped(
+    e: &Env,
+    client: &TycoonTokenClient,
+    admin: &Address,
+    initial_supply: i128,
+    max_supply: i128,
+    emission_schedule: Option<EmissionSchedule>,
+) {
+    client.initialize(
+        admin,
+        &initial_supply,
+        &max_supply,
+        &DECIMALS,
+        &String::from_str(e, "Tycoon"),
+        &String::from_str(e, "TYC"),
+        &emission_schedule,
+        &true,
+    );
+}
 
 #[test]
 fn test_initialization() {
@@ -12,11 +44,11 @@ fn test_initialization() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     assert_eq!(client.name(), String::from_str(&e, "Tycoon"));
     assert_eq!(client.symbol(), String::from_str(&e, "TYC"));
-    assert_eq!(client.decimals(), 18);
+    assert_eq!(client.decimals(), DECIMALS);
     assert_eq!(client.balance(&admin), INITIAL_SUPPLY);
     assert_eq!(client.total_supply(), INITIAL_SUPPLY);
     assert_eq!(client.admin(), admin);
@@ -32,8 +64,8 @@ fn test_cannot_reinitialize() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 }
 
 #[test]
@@ -46,7 +78,7 @@ fn test_admin_can_mint() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let mint_amount: i128 = 1_000_000_000_000_000_000_000;
     client.mint(&user, &mint_amount);
@@ -66,7 +98,7 @@ fn test_cannot_mint_zero() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
     client.mint(&user, &0);
 }
 
@@ -80,7 +112,7 @@ fn test_transfer() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let amount: i128 = 500_000_000_000_000_000_000_000_000;
     client.transfer(&admin, &user, &amount);
@@ -100,7 +132,7 @@ fn test_transfer_insufficient_balance() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
     client.transfer(&admin, &user, &(INITIAL_SUPPLY + 1));
 }
 
@@ -115,7 +147,7 @@ fn test_approve_and_transfer_from() {
     let spender = Address::generate(&e);
     let recipient = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     let transfer: i128 = 50_000_000_000_000_000_000_000_000;
@@ -142,7 +174,7 @@ fn test_transfer_from_insufficient_allowance() {
     let spender = Address::generate(&e);
     let recipient = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     client.approve(&admin, &spender, &allowance, &0);
@@ -158,7 +190,7 @@ fn test_burn() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let burn_amount: i128 = 100_000_000_000_000_000_000_000_000;
     client.burn(&admin, &burn_amount);
@@ -177,7 +209,7 @@ fn test_burn_insufficient_balance() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
     client.burn(&admin, &(INITIAL_SUPPLY + 1));
 }
 
@@ -191,7 +223,7 @@ fn test_burn_from() {
     let admin = Address::generate(&e);
     let spender = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     let burn_amount: i128 = 50_000_000_000_000_000_000_000_000;
@@ -215,7 +247,7 @@ fn test_burn_from_insufficient_allowance() {
     let admin = Address::generate(&e);
     let spender = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     client.approve(&admin, &spender, &allowance, &0);
@@ -223,7 +255,7 @@ fn test_burn_from_insufficient_allowance() {
 }
 
 #[test]
-fn test_set_admin() {
+fn test_two_step_admin_transfer() {
     let e = Env::default();
     e.mock_all_auths();
 
@@ -232,10 +264,17 @@ fn test_set_admin() {
     let admin = Address::generate(&e);
     let new_admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
-    client.set_admin(&new_admin);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer_ownership(&new_admin);
+
+    // Nothing changes until the new admin accepts
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.pending_owner(), Some(new_admin.clone()));
 
+    client.accept_ownership();
+    assert_eq!(e.auths()[0].0, new_admin);
     assert_eq!(client.admin(), new_admin);
+    assert_eq!(client.pending_owner(), None);
 }
 
 #[test]
@@ -249,11 +288,1035 @@ fn test_new_admin_can_mint() {
     let new_admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
-    client.set_admin(&new_admin);
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer_ownership(&new_admin);
+    client.accept_ownership();
 
     let mint_amount: i128 = 1_000_000_000_000_000_000_000;
     client.mint(&user, &mint_amount);
 
+    assert_eq!(e.auths()[0].0, new_admin);
     assert_eq!(client.balance(&user), mint_amount);
 }
+
+#[test]
+fn test_renounced_admin_cannot_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.renounce_ownership();
+
+    assert!(client.try_mint(&admin, &1).is_err());
+    assert!(client.try_add_minter(&admin, &1).is_err());
+}
+
+#[test]
+fn test_migrate_legacy_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    // State left by a release from before the ownership module
+    e.as_contract(&contract_id, || {
+        e.storage().instance().set(&DataKey::Initialized, &true);
+        e.storage().instance().set(&DataKey::Admin, &admin);
+    });
+    assert!(client.try_admin().is_err());
+
+    assert_eq!(client.migrate_admin(), admin);
+    assert_eq!(client.admin(), admin);
+    client.add_minter(&Address::generate(&e), &1);
+    assert!(client.try_migrate_admin().is_err());
+    e.as_contract(&contract_id, || {
+        assert!(!e.storage().instance().has(&DataKey::Admin));
+    });
+}
+
+#[test]
+fn test_allowance_expires() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.ledger().set_sequence_number(100);
+    client.approve(&admin, &spender, &1_000, &200);
+    assert_eq!(client.allowance(&admin, &spender), 1_000);
+
+    e.ledger().set_sequence_number(200);
+    assert_eq!(client.allowance(&admin, &spender), 1_000);
+
+    e.ledger().set_sequence_number(201);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient allowance")]
+fn test_transfer_from_after_expiration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.ledger().set_sequence_number(100);
+    client.approve(&admin, &spender, &1_000, &150);
+
+    e.ledger().set_sequence_number(151);
+    client.transfer_from(&spender, &admin, &recipient, &1);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient allowance")]
+fn test_burn_from_after_expiration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.ledger().set_sequence_number(100);
+    client.approve(&admin, &spender, &1_000, &150);
+
+    e.ledger().set_sequence_number(151);
+    client.burn_from(&spender, &admin, &1);
+}
+
+#[test]
+fn test_transfer_from_keeps_expiration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.ledger().set_sequence_number(100);
+    client.approve(&admin, &spender, &1_000, &150);
+    client.transfer_from(&spender, &admin, &recipient, &400);
+    assert_eq!(client.allowance(&admin, &spender), 600);
+
+    e.ledger().set_sequence_number(151);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "Expiration ledger is in the past")]
+fn test_approve_with_past_expiration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.ledger().set_sequence_number(100);
+    client.approve(&admin, &spender, &1_000, &99);
+}
+
+#[test]
+fn test_approve_zero_with_past_expiration_revokes() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.ledger().set_sequence_number(100);
+    client.approve(&admin, &spender, &1_000, &200);
+    client.approve(&admin, &spender, &0, &0);
+
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+fn test_metadata_is_configurable() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    client.initialize(
+        &admin,
+        &0,
+        &MAX_SUPPLY,
+        &18,
+        &String::from_str(&e, "Tycoon Test"),
+        &String::from_str(&e, "TYCT"),
+        &None,
+        &false,
+    );
+
+    assert_eq!(client.decimals(), 18);
+    assert_eq!(client.name(), String::from_str(&e, "Tycoon Test"));
+    assert_eq!(client.symbol(), String::from_str(&e, "TYCT"));
+}
+
+#[test]
+#[should_panic(expected = "Decimal must not be greater than 18")]
+fn test_initialize_rejects_too_many_decimals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &MAX_SUPPLY,
+        &19,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &None,
+        &false,
+    );
+}
+
+// -----------------------------------------------------------------------
+// Max supply and emission schedule
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_max_supply_views() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize_capped(&e, &client, &admin, 400, 1_000, None);
+
+    assert_eq!(client.max_supply(), 1_000);
+    assert_eq!(client.remaining_mintable_supply(), 600);
+    assert_eq!(client.emission_schedule(), None);
+    assert_eq!(client.current_period_allowance(), 600);
+}
+
+#[test]
+fn test_mint_up_to_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    initialize_capped(&e, &client, &admin, 400, 1_000, None);
+    client.mint(&user, &600);
+
+    assert_eq!(client.total_supply(), 1_000);
+    assert_eq!(client.remaining_mintable_supply(), 0);
+    assert_eq!(client.current_period_allowance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Max supply exceeded")]
+fn test_cannot_mint_past_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize_capped(&e, &client, &admin, 400, 1_000, None);
+    client.mint(&admin, &601);
+}
+
+#[test]
+fn test_burn_frees_room_under_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize_capped(&e, &client, &admin, 1_000, 1_000, None);
+    client.burn(&admin, &250);
+
+    assert_eq!(client.remaining_mintable_supply(), 250);
+    client.mint(&admin, &250);
+    assert_eq!(client.total_supply(), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Initial supply exceeds max supply")]
+fn test_initial_supply_cannot_exceed_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize_capped(&e, &client, &admin, 1_001, 1_000, None);
+}
+
+#[test]
+#[should_panic(expected = "Emission period must be positive")]
+fn test_emission_period_must_be_positive() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    let schedule = EmissionSchedule {
+        period_ledgers: 0,
+        max_per_period: 100,
+    };
+    initialize_capped(&e, &client, &admin, 0, 1_000, Some(schedule));
+}
+
+#[test]
+fn test_emission_schedule_limits_each_period() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(1_000);
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let schedule = EmissionSchedule {
+        period_ledgers: 100,
+        max_per_period: 300,
+    };
+    initialize_capped(&e, &client, &admin, 0, 1_000, Some(schedule.clone()));
+    assert_eq!(client.emission_schedule(), Some(schedule));
+    assert_eq!(client.current_period_allowance(), 300);
+
+    client.mint(&user, &200);
+    assert_eq!(client.current_period_allowance(), 100);
+    assert!(client.try_mint(&user, &101).is_err());
+    client.mint(&user, &100);
+    assert_eq!(client.current_period_allowance(), 0);
+
+    // Last ledger of the first period is still capped
+    e.ledger().set_sequence_number(1_099);
+    assert!(client.try_mint(&user, &1).is_err());
+
+    // Next period starts with a fresh allowance
+    e.ledger().set_sequence_number(1_100);
+    assert_eq!(client.current_period_allowance(), 300);
+    client.mint(&user, &300);
+    assert_eq!(client.balance(&user), 600);
+}
+
+#[test]
+fn test_period_allowance_bounded_by_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    let schedule = EmissionSchedule {
+        period_ledgers: 100,
+        max_per_period: 300,
+    };
+    initialize_capped(&e, &client, &admin, 900, 1_000, Some(schedule));
+
+    assert_eq!(client.current_period_allowance(), 100);
+    assert!(client.try_mint(&admin, &101).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Emission cap exceeded for current period")]
+fn test_cannot_mint_past_period_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    let schedule = EmissionSchedule {
+        period_ledgers: 100,
+        max_per_period: 300,
+    };
+    initialize_capped(&e, &client, &admin, 0, 1_000, Some(schedule));
+    client.mint(&admin, &301);
+}
+
+// -----------------------------------------------------------------------
+// Minters
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_add_and_remove_minter() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    assert!(!client.is_minter(&minter));
+    assert_eq!(client.minter_info(&minter), None);
+
+    client.add_minter(&minter, &1_000);
+    assert_eq!(e.auths()[0].0, admin);
+    assert!(client.is_minter(&minter));
+    assert_eq!(
+        client.minter_info(&minter),
+        Some(MinterInfo {
+            quota: 1_000,
+            minted: 0
+        })
+    );
+
+    client.remove_minter(&minter);
+    assert!(!client.is_minter(&minter));
+    assert_eq!(client.minter_remaining_quota(&minter), 0);
+}
+
+#[test]
+fn test_minter_mints_within_quota() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.add_minter(&minter, &1_000);
+
+    client.minter_mint(&minter, &user, &600);
+    assert_eq!(e.auths()[0].0, minter);
+    assert_eq!(client.balance(&user), 600);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY + 600);
+    assert_eq!(client.minter_remaining_quota(&minter), 400);
+
+    client.minter_mint(&minter, &user, &400);
+    assert_eq!(client.minter_remaining_quota(&minter), 0);
+}
+
+#[test]
+#[should_panic(expected = "Minter quota exceeded")]
+fn test_minter_cannot_exceed_quota() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.add_minter(&minter, &1_000);
+    client.minter_mint(&minter, &minter, &600);
+    client.minter_mint(&minter, &minter, &401);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: not a minter")]
+fn test_non_minter_cannot_minter_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.minter_mint(&stranger, &stranger, &1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: not a minter")]
+fn test_removed_minter_cannot_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.add_minter(&minter, &1_000);
+    client.remove_minter(&minter);
+    client.minter_mint(&minter, &minter, &1);
+}
+
+#[test]
+#[should_panic(expected = "Already a minter")]
+fn test_cannot_add_minter_twice() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.add_minter(&minter, &1_000);
+    client.add_minter(&minter, &2_000);
+}
+
+#[test]
+fn test_set_minter_quota_keeps_minted_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.add_minter(&minter, &1_000);
+    client.minter_mint(&minter, &minter, &800);
+
+    client.set_minter_quota(&minter, &500);
+    assert_eq!(client.minter_remaining_quota(&minter), 0);
+    assert!(client.try_minter_mint(&minter, &minter, &1).is_err());
+
+    client.set_minter_quota(&minter, &1_500);
+    assert_eq!(client.minter_remaining_quota(&minter), 700);
+}
+
+#[test]
+fn test_minter_bound_by_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize_capped(&e, &client, &admin, 900, 1_000, None);
+    client.add_minter(&minter, &500);
+
+    assert!(client.try_minter_mint(&minter, &minter, &101).is_err());
+    assert_eq!(client.minter_remaining_quota(&minter), 500);
+}
+
+#[test]
+fn test_only_admin_can_add_minter() {
+    let e = Env::default();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    e.mock_all_auths();
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+
+    e.set_auths(&[]);
+    assert!(client.try_add_minter(&minter, &1_000).is_err());
+}
+
+// -----------------------------------------------------------------------
+// Freezing and clawback
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_frozen_account_cannot_send_or_receive() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let cheater = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &cheater, &1_000);
+
+    client.freeze(&cheater);
+    assert_eq!(e.auths()[0].0, admin);
+    assert!(client.is_frozen(&cheater));
+
+    assert!(client.try_transfer(&cheater, &admin, &1).is_err());
+    assert!(client.try_transfer(&admin, &cheater, &1).is_err());
+    assert!(client.try_mint(&cheater, &1).is_err());
+    assert_eq!(client.balance(&cheater), 1_000);
+
+    client.unfreeze(&cheater);
+    assert!(!client.is_frozen(&cheater));
+    client.transfer(&cheater, &admin, &1_000);
+    assert_eq!(client.balance(&cheater), 0);
+}
+
+#[test]
+fn test_frozen_account_blocks_allowance_spending() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let cheater = Address::generate(&e);
+    let accomplice = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &cheater, &1_000);
+    client.approve(&cheater, &accomplice, &1_000, &100);
+    client.freeze(&cheater);
+
+    assert!(client
+        .try_transfer_from(&accomplice, &cheater, &accomplice, &500)
+        .is_err());
+    assert!(client.try_burn_from(&accomplice, &cheater, &500).is_err());
+    assert_eq!(client.allowance(&cheater, &accomplice), 1_000);
+}
+
+#[test]
+fn test_clawback_moves_frozen_balance_to_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let cheater = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &cheater, &1_000);
+    client.set_treasury(&treasury);
+    client.freeze(&cheater);
+
+    client.clawback(&cheater, &700);
+
+    assert_eq!(client.balance(&cheater), 300);
+    assert_eq!(client.balance(&treasury), 700);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
+
+#[test]
+#[should_panic(expected = "Account is not frozen")]
+fn test_cannot_clawback_unfrozen_account() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &user, &1_000);
+    client.set_treasury(&admin);
+    client.clawback(&user, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Treasury not set")]
+fn test_clawback_requires_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let cheater = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &cheater, &1_000);
+    client.freeze(&cheater);
+    client.clawback(&cheater, &1_000);
+}
+
+#[test]
+fn test_disable_freezing_is_permanent() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &user, &1_000);
+    client.freeze(&user);
+
+    client.disable_freezing();
+    assert!(!client.is_freezable());
+
+    // Frozen accounts are released and nothing can be frozen again
+    assert!(!client.is_frozen(&user));
+    client.transfer(&user, &admin, &1_000);
+    assert!(client.try_freeze(&user).is_err());
+    assert!(client.try_disable_freezing().is_err());
+}
+
+#[test]
+#[should_panic(expected = "Freezing is disabled")]
+fn test_cannot_freeze_when_not_freezable() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &MAX_SUPPLY,
+        &DECIMALS,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &None,
+        &false,
+    );
+    client.freeze(&Address::generate(&e));
+}
+
+// -----------------------------------------------------------------------
+// Pausing
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_pause_halts_transfers_mints_and_burns() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &user, &1_000);
+    client.approve(&user, &spender, &1_000, &100);
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+
+    assert!(client.try_transfer(&user, &admin, &1).is_err());
+    assert!(client
+        .try_transfer_from(&spender, &user, &spender, &1)
+        .is_err());
+    assert!(client.try_burn(&user, &1).is_err());
+    assert!(client.try_burn_from(&spender, &user, &1).is_err());
+    assert!(client.try_mint(&user, &1).is_err());
+
+    // Views keep working
+    assert_eq!(client.balance(&user), 1_000);
+    assert_eq!(client.allowance(&user, &spender), 1_000);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.transfer(&user, &admin, &1);
+    assert_eq!(client.balance(&user), 999);
+}
+
+#[test]
+#[should_panic(expected = "Token is paused")]
+fn test_minter_cannot_mint_while_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.add_minter(&minter, &1_000);
+    client.pause(&admin);
+    client.minter_mint(&minter, &minter, &1);
+}
+
+#[test]
+fn test_pauser_can_pause_and_unpause() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let pauser = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.set_pauser(&Some(pauser.clone()));
+    assert_eq!(client.pauser(), Some(pauser.clone()));
+
+    client.pause(&pauser);
+    assert_eq!(e.auths()[0].0, pauser);
+    assert!(client.is_paused());
+
+    client.unpause(&pauser);
+    assert!(!client.is_paused());
+
+    client.set_pauser(&None);
+    assert_eq!(client.pauser(), None);
+    assert!(client.try_pause(&pauser).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only admin or pauser can pause")]
+fn test_stranger_cannot_pause() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.pause(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Token is not paused")]
+fn test_cannot_unpause_when_not_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.unpause(&admin);
+}
+
+// ============================================================
+// Permits
+// ============================================================
+
+fn permit_signer(e: &Env, client: &TycoonTokenClient, owner: &Address) -> SigningKey {
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    client.set_permit_key(
+        owner,
+        &Some(BytesN::from_array(
+            e,
+            &signing_key.verifying_key().to_bytes(),
+        )),
+    );
+    signing_key
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign_permit(
+    e: &Env,
+    client: &TycoonTokenClient,
+    signing_key: &SigningKey,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> BytesN<64> {
+    let payload = client.permit_payload(owner, spender, &amount, &expiration_ledger, &nonce);
+    let mut message = std::vec![0u8; payload.len() as usize];
+    payload.copy_into_slice(&mut message);
+    BytesN::from_array(e, &signing_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_permit_sets_allowance_without_owner_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    client.transfer(&admin, &owner, &1000);
+    let signing_key = permit_signer(&e, &client, &owner);
+    assert_eq!(client.permit_nonce(&owner), 0);
+
+    let signature = sign_permit(&e, &client, &signing_key, &owner, &spender, 500, 200, 0);
+    e.set_auths(&[]);
+    client.permit(&owner, &spender, &500, &200, &0, &signature);
+
+    assert!(e.auths().is_empty());
+    assert_eq!(client.allowance(&owner, &spender), 500);
+    assert_eq!(client.permit_nonce(&owner), 1);
+
+    e.mock_all_auths();
+    client.transfer_from(&spender, &owner, &recipient, &300);
+    assert_eq!(client.balance(&recipient), 300);
+    assert_eq!(client.allowance(&owner, &spender), 200);
+}
+
+#[test]
+#[should_panic(expected = "Invalid nonce")]
+fn test_permit_cannot_be_replayed() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    let signing_key = permit_signer(&e, &client, &owner);
+    let signature = sign_permit(&e, &client, &signing_key, &owner, &spender, 500, 200, 0);
+
+    client.permit(&owner, &spender, &500, &200, &0, &signature);
+    client.approve(&owner, &spender, &0, &200);
+    client.permit(&owner, &spender, &500, &200, &0, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Invalid nonce")]
+fn test_permit_rejects_future_nonce() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    let signing_key = permit_signer(&e, &client, &owner);
+    let signature = sign_permit(&e, &client, &signing_key, &owner, &spender, 500, 200, 1);
+
+    client.permit(&owner, &spender, &500, &200, &1, &signature);
+}
+
+#[test]
+fn test_permit_rejects_tampered_message() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    let signing_key = permit_signer(&e, &client, &owner);
+    let signature = sign_permit(&e, &client, &signing_key, &owner, &spender, 500, 200, 0);
+
+    assert!(client
+        .try_permit(&owner, &spender, &5000, &200, &0, &signature)
+        .is_err());
+    assert!(client
+        .try_permit(&owner, &Address::generate(&e), &500, &200, &0, &signature)
+        .is_err());
+    assert_eq!(client.allowance(&owner, &spender), 0);
+    assert_eq!(client.permit_nonce(&owner), 0);
+}
+
+#[test]
+fn test_permit_rejects_other_key() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    permit_signer(&e, &client, &owner);
+    let attacker = SigningKey::from_bytes(&[9; 32]);
+    let signature = sign_permit(&e, &client, &attacker, &owner, &spender, 500, 200, 0);
+
+    assert!(client
+        .try_permit(&owner, &spender, &500, &200, &0, &signature)
+        .is_err());
+    assert_eq!(client.allowance(&owner, &spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "Permit key not set")]
+fn test_permit_requires_registered_key() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    let signing_key = permit_signer(&e, &client, &owner);
+    let signature = sign_permit(&e, &client, &signing_key, &owner, &spender, 500, 200, 0);
+
+    client.set_permit_key(&owner, &None);
+    assert_eq!(client.permit_key(&owner), None);
+    client.permit(&owner, &spender, &500, &200, &0, &signature);
+}
+
+#[test]
+fn test_set_permit_key_requires_owner_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+
+    initialize(&e, &client, &admin, INITIAL_SUPPLY);
+    let key = BytesN::from_array(&e, &[1; 32]);
+    client.set_permit_key(&owner, &Some(key.clone()));
+
+    assert_eq!(e.auths()[0].0, owner);
+    assert_eq!(client.permit_key(&owner), Some(key));
+}
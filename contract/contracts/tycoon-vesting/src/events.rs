@@ -0,0 +1,34 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when the admin funds a new schedule for `beneficiary`.
+#[contractevent(topics = ["ScheduleCreated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleCreated {
+    #[topic]
+    pub beneficiary: Address,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub total: i128,
+    pub revocable: bool,
+}
+
+/// Emitted when a beneficiary claims vested tokens.
+#[contractevent(topics = ["Claimed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claimed {
+    #[topic]
+    pub beneficiary: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin revokes a schedule. `unvested` went back to the
+/// admin; `vested` stays claimable by the beneficiary.
+#[contractevent(topics = ["Revoked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Revoked {
+    #[topic]
+    pub beneficiary: Address,
+    pub vested: i128,
+    pub unvested: i128,
+}
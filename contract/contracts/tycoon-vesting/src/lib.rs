@@ -0,0 +1,264 @@
+#![no_std]
+
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+pub use storage::VestingSchedule;
+use tycoon_lib::ownership;
+
+#[contract]
+pub struct TycoonVesting;
+
+#[contractimpl]
+impl TycoonVesting {
+    /// Initialize the contract with its admin and the TYC token it vests.
+    ///
+    /// Must be called exactly once. `admin` must sign the transaction.
+    ///
+    /// # Panics
+    /// - `"Contract already initialized"` if called more than once.
+    pub fn initialize(env: Env, admin: Address, token: Address) {
+        if ownership::is_owner_set(&env) {
+            panic!("Contract already initialized");
+        }
+
+        admin.require_auth();
+
+        ownership::init_owner(&env, &admin);
+        storage::set_token(&env, &token);
+    }
+
+    /// Lock `total` TYC for `beneficiary` (admin only). The tokens are pulled
+    /// from the admin, then vest linearly from `start` until
+    /// `start + duration`; nothing can be claimed before `start + cliff`.
+    /// `start` may be in the past to honour an earlier grant date.
+    ///
+    /// Emits `ScheduleCreated`.
+    ///
+    /// # Panics
+    /// - `"Schedule already exists"` — `beneficiary` already has a schedule.
+    /// - `"Amount must be positive"` — `total` is zero or negative.
+    /// - `"Duration must be positive"` — `duration` is zero.
+    /// - `"Cliff cannot exceed duration"` — `cliff > duration`.
+    pub fn create_schedule(
+        env: Env,
+        beneficiary: Address,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        total: i128,
+        revocable: bool,
+    ) {
+        let admin = ownership::require_owner(&env);
+
+        if storage::get_schedule(&env, &beneficiary).is_some() {
+            panic!("Schedule already exists");
+        }
+        if total <= 0 {
+            panic!("Amount must be positive");
+        }
+        if duration == 0 {
+            panic!("Duration must be positive");
+        }
+        if cliff > duration {
+            panic!("Cliff cannot exceed duration");
+        }
+        start.checked_add(duration).expect("Schedule end overflows");
+
+        let token = token::Client::new(&env, &storage::get_token(&env));
+        token.transfer(&admin, env.current_contract_address(), &total);
+
+        storage::set_schedule(
+            &env,
+            &beneficiary,
+            &VestingSchedule {
+                start,
+                cliff,
+                duration,
+                total,
+                claimed: 0,
+                revocable,
+                revoked: false,
+            },
+        );
+
+        events::ScheduleCreated {
+            beneficiary,
+            start,
+            cliff,
+            duration,
+            total,
+            revocable,
+        }
+        .publish(&env);
+    }
+
+    /// Transfer every vested but unclaimed token to `beneficiary`, who must
+    /// sign. Returns the amount claimed.
+    ///
+    /// Emits `Claimed`.
+    ///
+    /// # Panics
+    /// - `"Schedule not found"` — `beneficiary` has no schedule.
+    /// - `"Nothing to claim"` — before the cliff, or everything vested so far
+    ///   has already been claimed.
+    pub fn claim(env: Env, beneficiary: Address) -> i128 {
+        beneficiary.require_auth();
+
+        let mut schedule = storage::get_schedule(&env, &beneficiary)
+            .unwrap_or_else(|| panic!("Schedule not found"));
+        let amount = vested_amount(&env, &schedule) - schedule.claimed;
+        if amount <= 0 {
+            panic!("Nothing to claim");
+        }
+
+        schedule.claimed += amount;
+        storage::set_schedule(&env, &beneficiary, &schedule);
+
+        let token = token::Client::new(&env, &storage::get_token(&env));
+        token.transfer(&env.current_contract_address(), &beneficiary, &amount);
+
+        events::Claimed {
+            beneficiary,
+            amount,
+        }
+        .publish(&env);
+        amount
+    }
+
+    /// Stop `beneficiary`'s schedule (admin only). Whatever has vested so far
+    /// stays claimable by the beneficiary; the unvested remainder is returned
+    /// to the admin.
+    ///
+    /// Emits `Revoked`.
+    ///
+    /// # Panics
+    /// - `"Schedule not found"` — `beneficiary` has no schedule.
+    /// - `"Schedule is not revocable"` — created with `revocable = false`.
+    /// - `"Schedule already revoked"` — revoked before.
+    pub fn revoke(env: Env, beneficiary: Address) {
+        let admin = ownership::require_owner(&env);
+
+        let mut schedule = storage::get_schedule(&env, &beneficiary)
+            .unwrap_or_else(|| panic!("Schedule not found"));
+        if !schedule.revocable {
+            panic!("Schedule is not revocable");
+        }
+        if schedule.revoked {
+            panic!("Schedule already revoked");
+        }
+
+        let vested = vested_amount(&env, &schedule);
+        let unvested = schedule.total - vested;
+        schedule.total = vested;
+        schedule.revoked = true;
+        storage::set_schedule(&env, &beneficiary, &schedule);
+
+        if unvested > 0 {
+            let token = token::Client::new(&env, &storage::get_token(&env));
+            token.transfer(&env.current_contract_address(), &admin, &unvested);
+        }
+
+        events::Revoked {
+            beneficiary,
+            vested,
+            unvested,
+        }
+        .publish(&env);
+    }
+
+    // -----------------------------------------------------------------------
+    // Ownership (two-step, see tycoon_lib::ownership)
+    // -----------------------------------------------------------------------
+
+    /// Propose `new_admin` as the next admin (admin only). Control does not
+    /// move until `new_admin` calls `accept_ownership`.
+    ///
+    /// Emits `OwnershipTransferStarted`.
+    pub fn transfer_ownership(env: Env, new_admin: Address) {
+        ownership::transfer_ownership(&env, &new_admin);
+    }
+
+    /// Complete a pending transfer. Must be signed by the proposed admin.
+    ///
+    /// Emits `OwnershipTransferred`.
+    pub fn accept_ownership(env: Env) {
+        ownership::accept_ownership(&env);
+    }
+
+    /// Withdraw the pending proposal (admin only).
+    pub fn cancel_ownership_transfer(env: Env) {
+        ownership::cancel_ownership_transfer(&env);
+    }
+
+    /// Give up admin rights permanently (admin only). New schedules can no
+    /// longer be created and existing ones can no longer be revoked.
+    pub fn renounce_ownership(env: Env) {
+        ownership::renounce_ownership(&env);
+    }
+
+    // -----------------------------------------------------------------------
+    // View functions
+    // -----------------------------------------------------------------------
+
+    /// Returns the current admin.
+    pub fn get_admin(env: Env) -> Address {
+        ownership::get_owner(&env)
+    }
+
+    /// Returns the admin proposed by `transfer_ownership`, if any.
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        ownership::pending_owner(&env)
+    }
+
+    /// Returns the TYC token contract address.
+    pub fn get_token(env: Env) -> Address {
+        storage::get_token(&env)
+    }
+
+    /// Returns `beneficiary`'s schedule, if any.
+    pub fn get_schedule(env: Env, beneficiary: Address) -> Option<VestingSchedule> {
+        storage::get_schedule(&env, &beneficiary)
+    }
+
+    /// Tokens vested so far, claimed or not. Zero without a schedule.
+    pub fn vested_amount(env: Env, beneficiary: Address) -> i128 {
+        storage::get_schedule(&env, &beneficiary)
+            .map(|schedule| vested_amount(&env, &schedule))
+            .unwrap_or(0)
+    }
+
+    /// Tokens `claim` would transfer right now. Zero without a schedule.
+    pub fn claimable_amount(env: Env, beneficiary: Address) -> i128 {
+        storage::get_schedule(&env, &beneficiary)
+            .map(|schedule| vested_amount(&env, &schedule) - schedule.claimed)
+            .unwrap_or(0)
+    }
+}
+
+/// Tokens of `schedule` vested at the current ledger timestamp. A revoked
+/// schedule's `total` already holds the amount vested when it was revoked.
+fn vested_amount(env: &Env, schedule: &VestingSchedule) -> i128 {
+    if schedule.revoked {
+        return schedule.total;
+    }
+
+    let now = env.ledger().timestamp();
+    if now < schedule.start.saturating_add(schedule.cliff) {
+        return 0;
+    }
+    let elapsed = now - schedule.start;
+    if elapsed >= schedule.duration {
+        return schedule.total;
+    }
+
+    schedule
+        .total
+        .checked_mul(elapsed as i128)
+        .expect("Arithmetic overflow")
+        / schedule.duration as i128
+}
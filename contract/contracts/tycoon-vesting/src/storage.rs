@@ -0,0 +1,72 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+// -----------------------------------------------------------------------
+// DataKey
+// -----------------------------------------------------------------------
+
+/// Storage keys for the tycoon-vesting contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// The TYC token contract holding the vested allocations.
+    Token,
+    /// Maps beneficiary -> VestingSchedule.
+    Schedule(Address),
+}
+
+// -----------------------------------------------------------------------
+// VestingSchedule struct
+// -----------------------------------------------------------------------
+
+/// A beneficiary's allocation, released linearly from `start` over
+/// `duration` seconds with nothing claimable before `start + cliff`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Ledger timestamp vesting is measured from.
+    pub start: u64,
+    /// Seconds after `start` before any tokens vest. At the cliff, everything
+    /// accrued since `start` becomes claimable at once.
+    pub cliff: u64,
+    /// Seconds after `start` at which the full `total` has vested.
+    pub duration: u64,
+    /// Tokens granted. Reduced to the vested amount when revoked.
+    pub total: i128,
+    /// Tokens already claimed by the beneficiary.
+    pub claimed: i128,
+    /// Whether the admin may revoke the unvested remainder.
+    pub revocable: bool,
+    /// Set once the schedule has been revoked; nothing vests afterwards.
+    pub revoked: bool,
+}
+
+// -----------------------------------------------------------------------
+// Config
+// -----------------------------------------------------------------------
+
+pub fn set_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&DataKey::Token, token);
+}
+
+pub fn get_token(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Token)
+        .expect("Contract not initialized")
+}
+
+// -----------------------------------------------------------------------
+// Schedules
+// -----------------------------------------------------------------------
+
+pub fn get_schedule(env: &Env, beneficiary: &Address) -> Option<VestingSchedule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Schedule(beneficiary.clone()))
+}
+
+pub fn set_schedule(env: &Env, beneficiary: &Address, schedule: &VestingSchedule) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Schedule(beneficiary.clone()), schedule);
+}
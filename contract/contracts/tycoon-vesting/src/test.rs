@@ -0,0 +1,385 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+use tycoon_token::{TycoonToken, TycoonTokenClient};
+
+const SUPPLY: i128 = 10_000_000_000_000_000;
+const GRANT: i128 = 1_200_000;
+const START: u64 = 1_000;
+const CLIFF: u64 = 100;
+const DURATION: u64 = 1_200;
+
+// -----------------------------------------------------------------------
+// Test helpers
+// -----------------------------------------------------------------------
+
+struct Setup<'a> {
+    env: Env,
+    client: TycoonVestingClient<'a>,
+    token: TycoonTokenClient<'a>,
+    contract_id: Address,
+    admin: Address,
+}
+
+/// Deploys TYC and the vesting contract, both administered by `admin`, who
+/// holds the whole TYC supply.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let admin = Address::generate(&env);
+    let token_id = env.register(TycoonToken, ());
+    let token = TycoonTokenClient::new(&env, &token_id);
+    token.initialize(
+        &admin,
+        &SUPPLY,
+        &SUPPLY,
+        &7,
+        &String::from_str(&env, "Tycoon"),
+        &String::from_str(&env, "TYC"),
+        &None,
+        &false,
+    );
+
+    let contract_id = env.register(TycoonVesting, ());
+    let client = TycoonVestingClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id);
+
+    Setup {
+        env,
+        client,
+        token,
+        contract_id,
+        admin,
+    }
+}
+
+/// Creates the default revocable grant for a fresh beneficiary.
+fn grant(s: &Setup) -> Address {
+    let beneficiary = Address::generate(&s.env);
+    s.client
+        .create_schedule(&beneficiary, &START, &CLIFF, &DURATION, &GRANT, &true);
+    beneficiary
+}
+
+fn advance_to(env: &Env, timestamp: u64) {
+    env.ledger().set_timestamp(timestamp);
+}
+
+// -----------------------------------------------------------------------
+// Initialization
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_initialize() {
+    let s = setup();
+
+    assert_eq!(s.client.get_admin(), s.admin);
+    assert_eq!(s.client.get_token(), s.token.address);
+}
+
+#[test]
+#[should_panic(expected = "Contract already initialized")]
+fn test_cannot_reinitialize() {
+    let s = setup();
+    s.client
+        .initialize(&Address::generate(&s.env), &s.token.address);
+}
+
+// -----------------------------------------------------------------------
+// create_schedule
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_create_schedule_locks_tokens() {
+    let s = setup();
+    let beneficiary = Address::generate(&s.env);
+
+    s.client
+        .create_schedule(&beneficiary, &START, &CLIFF, &DURATION, &GRANT, &true);
+
+    let events = s.env.events().all();
+    let (emitter, topics, _) = events.last().unwrap();
+    assert_eq!(emitter, s.contract_id);
+    let expected: Vec<Val> =
+        (Symbol::new(&s.env, "ScheduleCreated"), beneficiary.clone()).into_val(&s.env);
+    assert_eq!(topics, expected);
+
+    assert_eq!(s.token.balance(&s.contract_id), GRANT);
+    assert_eq!(s.token.balance(&s.admin), SUPPLY - GRANT);
+    assert_eq!(
+        s.client.get_schedule(&beneficiary),
+        Some(VestingSchedule {
+            start: START,
+            cliff: CLIFF,
+            duration: DURATION,
+            total: GRANT,
+            claimed: 0,
+            revocable: true,
+            revoked: false,
+        })
+    );
+}
+
+#[test]
+fn test_create_schedule_requires_admin_auth() {
+    let s = setup();
+    grant(&s);
+
+    assert_eq!(s.env.auths()[0].0, s.admin);
+}
+
+#[test]
+#[should_panic(expected = "Schedule already exists")]
+fn test_cannot_create_second_schedule() {
+    let s = setup();
+    let beneficiary = grant(&s);
+    s.client
+        .create_schedule(&beneficiary, &START, &CLIFF, &DURATION, &GRANT, &true);
+}
+
+#[test]
+#[should_panic(expected = "Cliff cannot exceed duration")]
+fn test_cliff_cannot_exceed_duration() {
+    let s = setup();
+    s.client.create_schedule(
+        &Address::generate(&s.env),
+        &START,
+        &(DURATION + 1),
+        &DURATION,
+        &GRANT,
+        &true,
+    );
+}
+
+#[test]
+fn test_create_schedule_rejects_empty_grants() {
+    let s = setup();
+    let beneficiary = Address::generate(&s.env);
+
+    assert!(s
+        .client
+        .try_create_schedule(&beneficiary, &START, &0, &0, &GRANT, &true)
+        .is_err());
+    assert!(s
+        .client
+        .try_create_schedule(&beneficiary, &START, &CLIFF, &DURATION, &0, &true)
+        .is_err());
+    assert_eq!(s.client.get_schedule(&beneficiary), None);
+}
+
+// -----------------------------------------------------------------------
+// Vesting and claims
+// -----------------------------------------------------------------------
+
+#[test]
+#[should_panic(expected = "Nothing to claim")]
+fn test_nothing_claimable_before_cliff() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    advance_to(&s.env, START + CLIFF - 1);
+    assert_eq!(s.client.vested_amount(&beneficiary), 0);
+    assert_eq!(s.client.claimable_amount(&beneficiary), 0);
+    s.client.claim(&beneficiary);
+}
+
+#[test]
+fn test_vesting_is_linear_after_cliff() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    advance_to(&s.env, START + CLIFF);
+    assert_eq!(s.client.vested_amount(&beneficiary), GRANT / 12);
+
+    advance_to(&s.env, START + DURATION / 2);
+    assert_eq!(s.client.vested_amount(&beneficiary), GRANT / 2);
+
+    advance_to(&s.env, START + DURATION);
+    assert_eq!(s.client.vested_amount(&beneficiary), GRANT);
+
+    advance_to(&s.env, START + DURATION * 10);
+    assert_eq!(s.client.vested_amount(&beneficiary), GRANT);
+}
+
+#[test]
+fn test_claim_transfers_vested_tokens() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    advance_to(&s.env, START + DURATION / 4);
+    assert_eq!(s.client.claim(&beneficiary), GRANT / 4);
+    assert_eq!(s.env.auths()[0].0, beneficiary);
+
+    let events = s.env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let expected: Vec<Val> = (Symbol::new(&s.env, "Claimed"), beneficiary.clone()).into_val(&s.env);
+    assert_eq!(topics, expected);
+    let data: soroban_sdk::Map<Symbol, i128> = data.into_val(&s.env);
+    assert_eq!(data.get(Symbol::new(&s.env, "amount")), Some(GRANT / 4));
+
+    assert_eq!(s.token.balance(&beneficiary), GRANT / 4);
+    assert_eq!(s.client.claimable_amount(&beneficiary), 0);
+
+    advance_to(&s.env, START + DURATION);
+    assert_eq!(s.client.claimable_amount(&beneficiary), GRANT * 3 / 4);
+    assert_eq!(s.client.claim(&beneficiary), GRANT * 3 / 4);
+
+    assert_eq!(s.token.balance(&beneficiary), GRANT);
+    assert_eq!(s.token.balance(&s.contract_id), 0);
+    assert_eq!(s.client.get_schedule(&beneficiary).unwrap().claimed, GRANT);
+}
+
+#[test]
+#[should_panic(expected = "Nothing to claim")]
+fn test_cannot_claim_twice_in_same_ledger() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    advance_to(&s.env, START + DURATION / 2);
+    s.client.claim(&beneficiary);
+    s.client.claim(&beneficiary);
+}
+
+#[test]
+#[should_panic(expected = "Schedule not found")]
+fn test_claim_without_schedule() {
+    let s = setup();
+    s.client.claim(&Address::generate(&s.env));
+}
+
+#[test]
+fn test_claim_requires_beneficiary_auth() {
+    let s = setup();
+    let beneficiary = grant(&s);
+    advance_to(&s.env, START + DURATION);
+
+    s.env.set_auths(&[]);
+    assert!(s.client.try_claim(&beneficiary).is_err());
+    assert_eq!(s.token.balance(&beneficiary), 0);
+}
+
+#[test]
+fn test_schedule_starting_in_the_future() {
+    let s = setup();
+    let beneficiary = Address::generate(&s.env);
+    let start = START + 500;
+    s.client
+        .create_schedule(&beneficiary, &start, &0, &DURATION, &GRANT, &false);
+
+    assert_eq!(s.client.vested_amount(&beneficiary), 0);
+    advance_to(&s.env, start + DURATION / 2);
+    assert_eq!(s.client.vested_amount(&beneficiary), GRANT / 2);
+}
+
+#[test]
+fn test_views_without_schedule_are_zero() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(s.client.get_schedule(&stranger), None);
+    assert_eq!(s.client.vested_amount(&stranger), 0);
+    assert_eq!(s.client.claimable_amount(&stranger), 0);
+}
+
+// -----------------------------------------------------------------------
+// Revocation
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_revoke_returns_unvested_tokens_to_admin() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    advance_to(&s.env, START + DURATION / 4);
+    s.client.claim(&beneficiary);
+    advance_to(&s.env, START + DURATION / 2);
+    s.client.revoke(&beneficiary);
+
+    let events = s.env.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    let expected: Vec<Val> = (Symbol::new(&s.env, "Revoked"), beneficiary.clone()).into_val(&s.env);
+    assert_eq!(topics, expected);
+
+    assert_eq!(s.token.balance(&s.admin), SUPPLY - GRANT / 2);
+    assert_eq!(s.token.balance(&s.contract_id), GRANT / 4);
+
+    // Vesting stops at revocation; what vested before stays claimable.
+    advance_to(&s.env, START + DURATION);
+    assert_eq!(s.client.vested_amount(&beneficiary), GRANT / 2);
+    assert_eq!(s.client.claim(&beneficiary), GRANT / 4);
+    assert_eq!(s.token.balance(&beneficiary), GRANT / 2);
+    assert_eq!(s.token.balance(&s.contract_id), 0);
+}
+
+#[test]
+fn test_revoke_before_cliff_returns_everything() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    s.client.revoke(&beneficiary);
+
+    assert_eq!(s.token.balance(&s.admin), SUPPLY);
+    advance_to(&s.env, START + DURATION);
+    assert_eq!(s.client.claimable_amount(&beneficiary), 0);
+}
+
+#[test]
+#[should_panic(expected = "Schedule is not revocable")]
+fn test_cannot_revoke_irrevocable_schedule() {
+    let s = setup();
+    let beneficiary = Address::generate(&s.env);
+    s.client
+        .create_schedule(&beneficiary, &START, &CLIFF, &DURATION, &GRANT, &false);
+    s.client.revoke(&beneficiary);
+}
+
+#[test]
+#[should_panic(expected = "Schedule already revoked")]
+fn test_cannot_revoke_twice() {
+    let s = setup();
+    let beneficiary = grant(&s);
+    s.client.revoke(&beneficiary);
+    s.client.revoke(&beneficiary);
+}
+
+#[test]
+fn test_revoke_requires_admin_auth() {
+    let s = setup();
+    let beneficiary = grant(&s);
+
+    s.env.set_auths(&[]);
+    assert!(s.client.try_revoke(&beneficiary).is_err());
+    assert!(!s.client.get_schedule(&beneficiary).unwrap().revoked);
+}
+
+// -----------------------------------------------------------------------
+// Ownership
+// -----------------------------------------------------------------------
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let s = setup();
+    let new_admin = Address::generate(&s.env);
+
+    s.client.transfer_ownership(&new_admin);
+    assert_eq!(s.client.pending_owner(), Some(new_admin.clone()));
+    assert_eq!(s.client.get_admin(), s.admin);
+
+    s.client.accept_ownership();
+    assert_eq!(s.client.get_admin(), new_admin);
+    assert_eq!(s.client.pending_owner(), None);
+}
+
+#[test]
+#[should_panic(expected = "Owner not set")]
+fn test_renounced_admin_cannot_create_schedules() {
+    let s = setup();
+    s.client.renounce_ownership();
+    grant(&s);
+}